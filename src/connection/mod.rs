@@ -6,30 +6,29 @@ mod cursor;
 pub use self::cursor::Cursor;
 
 use db_result::DbResult;
-use expression::{AsExpression, Expression, NonAggregate};
-use expression::predicates::Eq;
+use expression::{Expression, EqAll};
 use persistable::{Insertable, InsertableColumns};
-use helper_types::{FindBy, Limit};
-use expression::helper_types::AsExpr;
+use helper_types::{Filter, Limit};
 use query_builder::{AsQuery, Query, QueryFragment};
 use query_builder::pg::PgQueryBuilder;
 use query_dsl::{FilterDsl, LimitDsl};
-use query_source::{Table, Column, Queriable};
+use query_source::{QuerySource, Table, Column, Queriable};
 use result::*;
 use self::pq_sys::*;
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
 use std::ffi::{CString, CStr};
 use std::{str, ptr, result};
-use types::{NativeSqlType, ToSql, ValuesToSql};
+use types::{self, NativeSqlType, ToSql, ValuesToSql, TypeName};
 
 pub struct Connection {
     internal_connection: *mut PGconn,
     transaction_depth: Cell<i32>,
+    type_oids: RefCell<HashMap<&'static str, (u32, u32)>>,
 }
 
 type PrimaryKey<T> = <T as Table>::PrimaryKey;
-type PkType<T> = <PrimaryKey<T> as Expression>::SqlType;
-type FindPredicate<T, PK> = Eq<PrimaryKey<T>, <PK as AsExpression<PkType<T>>>::Expression>;
+type FindPredicate<T, PK> = <PrimaryKey<T> as EqAll<PK>>::Output;
 
 impl Connection {
     pub fn establish(database_url: &str) -> ConnectionResult<Connection> {
@@ -41,6 +40,7 @@ impl Connection {
                 Ok(Connection {
                     internal_connection: connection_ptr,
                     transaction_depth: Cell::new(0),
+                    type_oids: RefCell::new(HashMap::new()),
                 })
             },
             _ => {
@@ -97,7 +97,7 @@ impl Connection {
         T: AsQuery,
         U: Queriable<T::SqlType>,
     {
-        let (sql, params, types) = self.prepare_query(&source.as_query());
+        let (sql, params, types) = try!(self.prepare_query(&source.as_query()));
         self.exec_sql_params(&sql, &params, &Some(types)).map(Cursor::new)
     }
 
@@ -154,43 +154,42 @@ impl Connection {
 
     pub fn find<T, U, PK>(&self, source: T, id: PK) -> Result<Option<U>> where
         T: Table + FilterDsl<FindPredicate<T, PK>>,
-        FindBy<T, T::PrimaryKey, PK>: LimitDsl,
-        U: Queriable<<Limit<FindBy<T, T::PrimaryKey, PK>> as Query>::SqlType>,
-        PK: AsExpression<PkType<T>>,
-        AsExpr<PK, T::PrimaryKey>: NonAggregate,
+        T::PrimaryKey: EqAll<PK>,
+        Filter<T, FindPredicate<T, PK>>: LimitDsl,
+        U: Queriable<<Limit<Filter<T, FindPredicate<T, PK>>> as Query>::SqlType>,
     {
         let pk = source.primary_key();
-        self.query_one(source.filter(pk.eq(id)).limit(1))
+        self.query_one(source.filter(pk.eq_all(id)).limit(1))
     }
 
-    pub fn insert<T, U, Out>(&self, _source: &T, records: U)
+    pub fn insert<T, U, Out>(&self, source: &T, records: U)
         -> Result<Cursor<<T::AllColumns as Expression>::SqlType, Out>> where
         T: Table,
         U: Insertable<T>,
         Out: Queriable<<T::AllColumns as Expression>::SqlType>,
     {
-        let (param_placeholders, params, param_types) = self.placeholders_for_insert(records);
-        let (returning, _, _) = self.prepare_query(&T::all_columns());
+        let (param_placeholders, params, param_types) = try!(self.placeholders_for_insert(records));
+        let (returning, _, _) = try!(self.prepare_query(&T::all_columns()));
         let sql = format!(
             "INSERT INTO {} ({}) VALUES {} RETURNING {}",
-            T::name(),
-            U::columns().names(),
+            try!(self.prepare_from_clause(source)),
+            try!(self.prepare_column_names(&U::columns())),
             param_placeholders,
             returning,
         );
         self.exec_sql_params(&sql, &params, &Some(param_types)).map(Cursor::new)
     }
 
-    pub fn insert_returning_count<T, U>(&self, _source: &T, records: U)
+    pub fn insert_returning_count<T, U>(&self, source: &T, records: U)
         -> Result<usize> where
         T: Table,
         U: Insertable<T>,
     {
-        let (param_placeholders, params, param_types) = self.placeholders_for_insert(records);
+        let (param_placeholders, params, param_types) = try!(self.placeholders_for_insert(records));
         let sql = format!(
             "INSERT INTO {} ({}) VALUES {}",
-            T::name(),
-            U::columns().names(),
+            try!(self.prepare_from_clause(source)),
+            try!(self.prepare_column_names(&U::columns())),
             &param_placeholders,
         );
         self.exec_sql_params(&sql, &params, &Some(param_types)).map(|r| r.rows_affected())
@@ -199,17 +198,34 @@ impl Connection {
     pub fn execute_returning_count<T>(&self, source: &T) -> Result<usize> where
         T: QueryFragment,
     {
-        let (sql, params, param_types) = self.prepare_query(source);
+        let (sql, params, param_types) = try!(self.prepare_query(source));
         self.exec_sql_params(&sql, &params, &Some(param_types))
             .map(|r| r.rows_affected())
     }
 
     fn prepare_query<T: QueryFragment>(&self, source: &T)
-        -> (String, Vec<Option<Vec<u8>>>, Vec<u32>)
+        -> Result<(String, Vec<Option<Vec<u8>>>, Vec<u32>)>
     {
         let mut query_builder = PgQueryBuilder::new(self);
-        source.to_sql(&mut query_builder).unwrap();
-        (query_builder.sql, query_builder.binds, query_builder.bind_types)
+        try!(source.to_sql(&mut query_builder)
+            .map_err(|e| Error::QueryBuilderError(e.to_string())));
+        Ok((query_builder.sql, query_builder.binds, query_builder.bind_types))
+    }
+
+    fn prepare_from_clause<T: QuerySource>(&self, source: &T) -> Result<String> {
+        let mut query_builder = PgQueryBuilder::new(self);
+        try!(source.from_clause(&mut query_builder)
+            .map_err(|e| Error::QueryBuilderError(e.to_string())));
+        Ok(query_builder.sql)
+    }
+
+    fn prepare_column_names<T: Table, C: InsertableColumns<T>>(&self, columns: &C)
+        -> Result<String>
+    {
+        let mut query_builder = PgQueryBuilder::new(self);
+        try!(columns.names_to_sql(&mut query_builder)
+            .map_err(|e| Error::QueryBuilderError(e.to_string())));
+        Ok(query_builder.sql)
     }
 
     fn execute_inner(&self, query: &str) -> Result<DbResult> {
@@ -220,14 +236,45 @@ impl Connection {
         last_error_message(self.internal_connection)
     }
 
+    /// Looks up the OID of a type which is not built into Postgres, such as an
+    /// enum or a type from an extension. The result is cached for the lifetime
+    /// of the connection.
+    pub fn type_oid(&self, type_name: TypeName) -> Result<u32> {
+        let cached_oids = self.type_oids.borrow().get(type_name.name).cloned();
+        let (oid, array_oid) = match cached_oids {
+            Some(oids) => oids,
+            None => {
+                let oids = try!(self.lookup_type_oids(type_name.name));
+                self.type_oids.borrow_mut().insert(type_name.name, oids);
+                oids
+            }
+        };
+        if type_name.is_array {
+            Ok(array_oid)
+        } else {
+            Ok(oid)
+        }
+    }
+
+    fn lookup_type_oids(&self, name: &str) -> Result<(u32, u32)> {
+        let query = "SELECT oid, typarray FROM pg_type WHERE oid = to_regtype($1)";
+        let mut oids = try!(self.query_sql_params::<(types::Integer, types::Integer), (i32, i32), types::Text, _>(
+            query, &name));
+        match oids.next() {
+            Some((oid, array_oid)) => Ok((oid as u32, array_oid as u32)),
+            None => Err(Error::QueryBuilderError(format!("Unknown type {}", name))),
+        }
+    }
+
     fn placeholders_for_insert<T, U>(&self, records: U)
-        -> (String, Vec<Option<Vec<u8>>>, Vec<u32>) where
+        -> Result<(String, Vec<Option<Vec<u8>>>, Vec<u32>)> where
         T: Table,
         U: Insertable<T>,
     {
         let mut query_builder = PgQueryBuilder::new(self);
-        records.values().to_insert_sql(&mut query_builder).unwrap();
-        (query_builder.sql, query_builder.binds, query_builder.bind_types)
+        try!(records.values().to_insert_sql(&mut query_builder)
+            .map_err(|e| Error::QueryBuilderError(e.to_string())));
+        Ok((query_builder.sql, query_builder.binds, query_builder.bind_types))
     }
 
     fn begin_transaction(&self) -> Result<usize> {
@@ -22,16 +22,16 @@ impl<T, U> Expression for Bound<T, U> where
     type SqlType = T;
 
     fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
-        self.item.values_to_sql().map(|mut values| {
-            out.push_bound_value::<T>(values.pop().unwrap());
+        self.item.values_to_sql().and_then(|mut values| {
+            out.push_bound_value::<T>(values.pop().unwrap())
         })
     }
 
     fn to_insert_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
-        self.item.values_to_sql().map(|mut values| {
+        self.item.values_to_sql().and_then(|mut values| {
             match values.pop().unwrap() {
                 values@Some(_) => out.push_bound_value::<T>(values),
-                None => out.push_sql("DEFAULT"),
+                None => Ok(out.push_sql("DEFAULT")),
             }
         })
     }
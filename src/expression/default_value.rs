@@ -0,0 +1,43 @@
+use query_builder::{QueryBuilder, BuildQueryResult};
+use std::marker::PhantomData;
+use super::Expression;
+use super::predicates::Eq;
+use query_builder::{Changeset, SelectableChangeset};
+use query_source::Column;
+use types::NativeSqlType;
+
+/// The `DEFAULT` keyword, for resetting a column to its default value in an
+/// update, e.g. `update(users).set(hair_color.eq(default()))`. It is only
+/// accepted as the right hand side of an assignment in a changeset.
+pub fn default<ST: NativeSqlType>() -> DefaultValue<ST> {
+    DefaultValue {
+        _marker: PhantomData,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DefaultValue<ST> {
+    _marker: PhantomData<ST>,
+}
+
+impl<ST: NativeSqlType> Expression for DefaultValue<ST> {
+    type SqlType = ST;
+
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        out.push_sql("DEFAULT");
+        Ok(())
+    }
+}
+
+impl<C: Column> Changeset for Eq<C, DefaultValue<C::SqlType>> {
+    type Target = C::Table;
+
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        try!(out.push_identifier(C::name()));
+        out.push_sql(" = DEFAULT");
+        Ok(())
+    }
+}
+
+impl<C: Column, QS> SelectableChangeset<QS> for Eq<C, DefaultValue<C::SqlType>> {
+}
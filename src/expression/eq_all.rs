@@ -0,0 +1,62 @@
+use query_source::Column;
+use super::{Expression, AsExpression, NonAggregate};
+use super::predicates::{And, Eq};
+use types::Bool;
+
+/// Compares one or more columns against the same number of values, joining the
+/// comparisons with `AND`. Used by `Connection::find` to look up rows by a
+/// primary key, which may be a tuple of columns.
+pub trait EqAll<Rhs> {
+    type Output: Expression<SqlType=Bool> + NonAggregate;
+
+    fn eq_all(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<C, Rhs> EqAll<Rhs> for C where
+    C: Column + NonAggregate,
+    Rhs: AsExpression<C::SqlType>,
+    Rhs::Expression: NonAggregate,
+{
+    type Output = Eq<C, Rhs::Expression>;
+
+    fn eq_all(self, rhs: Rhs) -> Self::Output {
+        self.eq(rhs)
+    }
+}
+
+macro_rules! eq_all_tuples {
+    ($Left:ident $LeftRhs:ident, $Right:ident $RightRhs:ident) => {
+        #[allow(non_snake_case)]
+        impl<$Left, $Right, $LeftRhs, $RightRhs> EqAll<($LeftRhs, $RightRhs)> for ($Left, $Right) where
+            $Left: EqAll<$LeftRhs>,
+            $Right: EqAll<$RightRhs>,
+        {
+            type Output = And<$Left::Output, $Right::Output>;
+
+            fn eq_all(self, rhs: ($LeftRhs, $RightRhs)) -> Self::Output {
+                let ($Left, $Right) = self;
+                let ($LeftRhs, $RightRhs) = rhs;
+                And::new($Left.eq_all($LeftRhs), $Right.eq_all($RightRhs))
+            }
+        }
+    };
+    ($Left:ident $LeftRhs:ident, $($T:ident $Rhs:ident),+) => {
+        #[allow(non_snake_case)]
+        impl<$Left, $LeftRhs, $($T),+, $($Rhs),+> EqAll<($LeftRhs, $($Rhs),+)> for ($Left, $($T),+) where
+            $Left: EqAll<$LeftRhs>,
+            ($($T),+): EqAll<($($Rhs),+)>,
+        {
+            type Output = And<$Left::Output, <($($T),+) as EqAll<($($Rhs),+)>>::Output>;
+
+            fn eq_all(self, rhs: ($LeftRhs, $($Rhs),+)) -> Self::Output {
+                let ($Left, $($T),+) = self;
+                let ($LeftRhs, $($Rhs),+) = rhs;
+                And::new($Left.eq_all($LeftRhs), ($($T),+).eq_all(($($Rhs),+)))
+            }
+        }
+
+        eq_all_tuples!($($T $Rhs),+);
+    };
+}
+
+eq_all_tuples!(A RA, B RB, C RC, D RD, E RE, F RF);
@@ -0,0 +1,79 @@
+use expression::{Expression, AsExpression};
+use expression::json::*;
+use expression::predicates::{Contains, IsContainedBy, HasKey, HasAnyKey, HasAllKeys};
+use types::{Array, Integer, Jsonb, JsonType, JsonbType, Text};
+
+/// Operators for `json` and `jsonb` expressions. Retrieving a value which does
+/// not exist returns `NULL`, so each of these return a nullable type.
+pub trait JsonExpressionMethods: Expression + Sized where
+    Self::SqlType: JsonType,
+{
+    /// Retrieves the value of the key `key` from an object (`->`).
+    fn get<T: AsExpression<Text>>(self, key: T) -> RetrieveAsJson<Self, T::Expression> {
+        RetrieveAsJson::new(self, key.as_expression())
+    }
+
+    /// Retrieves the element at `index` from an array (`->`).
+    fn get_index<T: AsExpression<Integer>>(self, index: T) -> RetrieveAsJson<Self, T::Expression> {
+        RetrieveAsJson::new(self, index.as_expression())
+    }
+
+    /// Retrieves the value of the key `key` from an object as text (`->>`).
+    fn get_text<T: AsExpression<Text>>(self, key: T) -> RetrieveAsText<Self, T::Expression> {
+        RetrieveAsText::new(self, key.as_expression())
+    }
+
+    /// Retrieves the element at `index` from an array as text (`->>`).
+    fn get_text_index<T: AsExpression<Integer>>(self, index: T)
+        -> RetrieveAsText<Self, T::Expression>
+    {
+        RetrieveAsText::new(self, index.as_expression())
+    }
+
+    /// Retrieves the value at the given path of keys and indexes (`#>`).
+    fn get_path<T: AsExpression<Array<Text>>>(self, path: T) -> RetrieveByPath<Self, T::Expression> {
+        RetrieveByPath::new(self, path.as_expression())
+    }
+}
+
+impl<T> JsonExpressionMethods for T where
+    T: Expression,
+    T::SqlType: JsonType,
+{
+}
+
+/// Operators which are only defined for `jsonb` expressions.
+pub trait JsonbExpressionMethods: Expression + Sized where
+    Self::SqlType: JsonbType,
+{
+    /// Whether this document contains `other` at the top level (`@>`).
+    fn contains<T: AsExpression<Jsonb>>(self, other: T) -> Contains<Self, T::Expression> {
+        Contains::new(self, other.as_expression())
+    }
+
+    /// Whether `other` contains this document at the top level (`<@`).
+    fn is_contained_by<T: AsExpression<Jsonb>>(self, other: T) -> IsContainedBy<Self, T::Expression> {
+        IsContainedBy::new(self, other.as_expression())
+    }
+
+    /// Whether `key` is a top level key of this document (`?`).
+    fn has_key<T: AsExpression<Text>>(self, key: T) -> HasKey<Self, T::Expression> {
+        HasKey::new(self, key.as_expression())
+    }
+
+    /// Whether any of `keys` is a top level key of this document (`?|`).
+    fn has_any_key<T: AsExpression<Array<Text>>>(self, keys: T) -> HasAnyKey<Self, T::Expression> {
+        HasAnyKey::new(self, keys.as_expression())
+    }
+
+    /// Whether all of `keys` are top level keys of this document (`?&`).
+    fn has_all_keys<T: AsExpression<Array<Text>>>(self, keys: T) -> HasAllKeys<Self, T::Expression> {
+        HasAllKeys::new(self, keys.as_expression())
+    }
+}
+
+impl<T> JsonbExpressionMethods for T where
+    T: Expression,
+    T::SqlType: JsonbType,
+{
+}
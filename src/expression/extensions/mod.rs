@@ -1,3 +1,7 @@
 mod interval_dsl;
+mod json_dsl;
+mod range_dsl;
 
 pub use self::interval_dsl::{MicroIntervalDsl, DayAndMonthIntervalDsl};
+pub use self::json_dsl::{JsonExpressionMethods, JsonbExpressionMethods};
+pub use self::range_dsl::RangeExpressionMethods;
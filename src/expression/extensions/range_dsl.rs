@@ -0,0 +1,53 @@
+use expression::{Expression, AsExpression};
+use expression::predicates::{Contains, IsContainedBy, Overlaps, IsAdjacentTo};
+use expression::range::{Lower, Upper};
+use types::RangeType;
+
+/// Operators for range expressions such as `int4range` or `tstzrange`.
+pub trait RangeExpressionMethods: Expression + Sized where
+    Self::SqlType: RangeType,
+{
+    /// Whether `element` is within this range (`@>`).
+    fn contains<T>(self, element: T) -> Contains<Self, T::Expression> where
+        T: AsExpression<<Self::SqlType as RangeType>::Subtype>,
+    {
+        Contains::new(self, element.as_expression())
+    }
+
+    /// Whether all of `other` is within this range (`@>`).
+    fn contains_range<T: AsExpression<Self::SqlType>>(self, other: T) -> Contains<Self, T::Expression> {
+        Contains::new(self, other.as_expression())
+    }
+
+    /// Whether all of this range is within `other` (`<@`).
+    fn is_contained_by<T: AsExpression<Self::SqlType>>(self, other: T) -> IsContainedBy<Self, T::Expression> {
+        IsContainedBy::new(self, other.as_expression())
+    }
+
+    /// Whether this range has any elements in common with `other` (`&&`).
+    fn overlaps_with<T: AsExpression<Self::SqlType>>(self, other: T) -> Overlaps<Self, T::Expression> {
+        Overlaps::new(self, other.as_expression())
+    }
+
+    /// Whether this range ends where `other` begins, or begins where it ends
+    /// (`-|-`).
+    fn is_adjacent_to<T: AsExpression<Self::SqlType>>(self, other: T) -> IsAdjacentTo<Self, T::Expression> {
+        IsAdjacentTo::new(self, other.as_expression())
+    }
+
+    /// The lower bound of this range, or `NULL` if it is empty or unbounded.
+    fn lower(self) -> Lower<Self> {
+        Lower::new(self)
+    }
+
+    /// The upper bound of this range, or `NULL` if it is empty or unbounded.
+    fn upper(self) -> Upper<Self> {
+        Upper::new(self)
+    }
+}
+
+impl<T> RangeExpressionMethods for T where
+    T: Expression,
+    T::SqlType: RangeType,
+{
+}
@@ -0,0 +1,54 @@
+use expression::{Expression, SelectableExpression, NonAggregate};
+use query_builder::{QueryBuilder, BuildQueryResult};
+use types::{JsonType, Nullable, Text};
+
+macro_rules! json_operator {
+    ($name:ident, $operator:expr, $sql_type:ty) => {
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name<T, U> {
+            left: T,
+            right: U,
+        }
+
+        impl<T, U> $name<T, U> {
+            pub fn new(left: T, right: U) -> Self {
+                $name {
+                    left: left,
+                    right: right,
+                }
+            }
+        }
+
+        impl<T, U> Expression for $name<T, U> where
+            T: Expression,
+            T::SqlType: JsonType,
+            U: Expression,
+        {
+            type SqlType = $sql_type;
+
+            fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+                try!(self.left.to_sql(out));
+                out.push_sql($operator);
+                self.right.to_sql(out)
+            }
+        }
+
+        impl<T, U, QS> SelectableExpression<QS> for $name<T, U> where
+            T: SelectableExpression<QS>,
+            U: SelectableExpression<QS>,
+            $name<T, U>: Expression,
+        {
+        }
+
+        impl<T, U> NonAggregate for $name<T, U> where
+            T: NonAggregate,
+            U: NonAggregate,
+            $name<T, U>: Expression,
+        {
+        }
+    }
+}
+
+json_operator!(RetrieveAsJson, " -> ", Nullable<<T::SqlType as JsonType>::NotNull>);
+json_operator!(RetrieveAsText, " ->> ", Nullable<Text>);
+json_operator!(RetrieveByPath, " #> ", Nullable<<T::SqlType as JsonType>::NotNull>);
@@ -4,25 +4,33 @@ pub mod ops;
 pub mod array_comparison;
 pub mod bound;
 pub mod count;
+pub mod default_value;
+pub mod eq_all;
 pub mod extensions;
 pub mod functions;
 pub mod grouped;
 pub mod helper_types;
+pub mod json;
 pub mod max;
 pub mod ordering;
 pub mod predicates;
+pub mod range;
+pub mod row;
 pub mod sql_literal;
 
 pub mod dsl {
     pub use super::array_comparison::any;
     pub use super::count::{count, count_star};
+    pub use super::default_value::default;
     pub use super::functions::date_and_time::{now, date};
     pub use super::max::max;
+    pub use super::row::row;
 
     pub use super::extensions::*;
 }
 
 pub use self::dsl::*;
+pub use self::eq_all::EqAll;
 pub use self::sql_literal::SqlLiteral;
 
 use query_builder::{QueryBuilder, BuildQueryResult};
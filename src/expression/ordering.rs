@@ -1,5 +1,5 @@
 use query_builder::{QueryBuilder, BuildQueryResult};
-use super::{Expression, SelectableExpression};
+use super::{Expression, SelectableExpression, NonAggregate};
 
 pub struct Desc<T> {
     expr: T,
@@ -30,3 +30,9 @@ impl<T, QS> SelectableExpression<QS> for Desc<T> where
     T: SelectableExpression<QS>,
 {
 }
+
+impl<T> NonAggregate for Desc<T> where
+    Desc<T>: Expression,
+    T: NonAggregate,
+{
+}
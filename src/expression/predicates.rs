@@ -48,9 +48,15 @@ macro_rules! infix_predicate {
 
 infix_predicate!(And, " AND ");
 infix_predicate!(Between, " BETWEEN ");
+infix_predicate!(Contains, " @> ");
 infix_predicate!(Eq, " = ");
 infix_predicate!(Gt, " > ");
 infix_predicate!(GtEq, " >= ");
+infix_predicate!(HasAllKeys, " ?& ");
+infix_predicate!(HasAnyKey, " ?| ");
+infix_predicate!(HasKey, " ? ");
+infix_predicate!(IsAdjacentTo, " -|- ");
+infix_predicate!(IsContainedBy, " <@ ");
 infix_predicate!(Like, " LIKE ");
 infix_predicate!(Lt, " < ");
 infix_predicate!(LtEq, " <= ");
@@ -58,12 +64,13 @@ infix_predicate!(NotBetween, " NOT BETWEEN ");
 infix_predicate!(NotEq, " != ");
 infix_predicate!(NotLike, " NOT LIKE ");
 infix_predicate!(Or, " OR ");
+infix_predicate!(Overlaps, " && ");
 
 use query_source::Column;
 
 impl<T, U> Changeset for Eq<T, U> where
     T: Column,
-    U: SelectableExpression<T::Table>,
+    U: Expression + NonAggregate,
     Eq<T, U>: Expression,
 {
     type Target = T::Table;
@@ -74,3 +81,9 @@ impl<T, U> Changeset for Eq<T, U> where
         Expression::to_sql(&self.right, out)
     }
 }
+
+impl<T, U, QS> SelectableChangeset<QS> for Eq<T, U> where
+    Eq<T, U>: Changeset,
+    U: SelectableExpression<QS> + NonAggregate,
+{
+}
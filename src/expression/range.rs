@@ -0,0 +1,49 @@
+use expression::{Expression, SelectableExpression, NonAggregate};
+use query_builder::{QueryBuilder, BuildQueryResult};
+use types::{Nullable, RangeType};
+
+macro_rules! range_function {
+    ($name:ident, $function:expr) => {
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name<T> {
+            range: T,
+        }
+
+        impl<T> $name<T> {
+            pub fn new(range: T) -> Self {
+                $name {
+                    range: range,
+                }
+            }
+        }
+
+        impl<T> Expression for $name<T> where
+            T: Expression,
+            T::SqlType: RangeType,
+        {
+            type SqlType = Nullable<<T::SqlType as RangeType>::Subtype>;
+
+            fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+                out.push_sql(concat!($function, "("));
+                try!(self.range.to_sql(out));
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+
+        impl<T, QS> SelectableExpression<QS> for $name<T> where
+            T: SelectableExpression<QS>,
+            $name<T>: Expression,
+        {
+        }
+
+        impl<T> NonAggregate for $name<T> where
+            T: NonAggregate,
+            $name<T>: Expression,
+        {
+        }
+    }
+}
+
+range_function!(Lower, "LOWER");
+range_function!(Upper, "UPPER");
@@ -0,0 +1,38 @@
+use query_builder::{QueryBuilder, BuildQueryResult};
+use super::{Expression, SelectableExpression, NonAggregate};
+use types::Record;
+
+/// Creates an anonymous record from a tuple of expressions, using `ROW(...)`.
+/// The record can be loaded into a tuple of the types of its fields.
+pub fn row<T: Expression>(fields: T) -> RowConstructor<T> {
+    RowConstructor {
+        fields: fields,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RowConstructor<T> {
+    fields: T,
+}
+
+impl<T: Expression> Expression for RowConstructor<T> {
+    type SqlType = Record<T::SqlType>;
+
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        out.push_sql("ROW(");
+        try!(self.fields.to_sql(out));
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<T, QS> SelectableExpression<QS> for RowConstructor<T> where
+    T: SelectableExpression<QS>,
+    RowConstructor<T>: Expression,
+{
+}
+
+impl<T: NonAggregate> NonAggregate for RowConstructor<T> where
+    RowConstructor<T>: Expression,
+{
+}
@@ -13,10 +13,17 @@ mod row;
 
 pub mod helper_types {
     pub use super::query_dsl::{
+        BoxedOutput as IntoBoxed,
+        DistinctOutput as Distinct,
+        DistinctOnOutput as DistinctOn,
         FilterOutput as Filter,
         FindByOutput as FindBy,
+        FullOuterJoinOutput as FullOuterJoin,
+        InnerJoinOutput as InnerJoin,
+        LeftOuterJoinOutput as LeftOuterJoin,
         LimitOutput as Limit,
         OrderOutput as Order,
+        RightOuterJoinOutput as RightOuterJoin,
         SelectOutput as Select,
     };
 }
@@ -1,19 +1,101 @@
 #[macro_export]
 macro_rules! table {
     (
-        $name:ident {
-            $($column_name:ident -> $Type:ty,)+
+        #[sql_name = $sql_name:expr]
+        $schema:ident . $name:ident $($rest:tt)*
+    ) => {
+        table! {
+            @table [$schema], $sql_name, $name $($rest)*
+        }
+    };
+    (
+        #[sql_name = $sql_name:expr]
+        $name:ident $($rest:tt)*
+    ) => {
+        table! {
+            @table [], $sql_name, $name $($rest)*
         }
+    };
+    (
+        $schema:ident . $name:ident $($rest:tt)*
     ) => {
         table! {
-            $name (id) {
-                $($column_name -> $Type,)+
+            @table [$schema], stringify!($name), $name $($rest)*
+        }
+    };
+    (
+        $name:ident $($rest:tt)*
+    ) => {
+        table! {
+            @table [], stringify!($name), $name $($rest)*
+        }
+    };
+    (
+        @table [$($schema:ident)*], $sql_name:expr, $name:ident {
+            $($body:tt)*
+        }
+    ) => {
+        table! {
+            @table [$($schema)*], $sql_name, $name (id) {
+                $($body)*
             }
         }
     };
     (
-        $name:ident ($pk:ident) {
-            $($column_name:ident -> $Type:ty,)+
+        @table [$($schema:ident)*], $sql_name:expr, $name:ident ($($pk:ident),+) {
+            $($body:tt)*
+        }
+    ) => {
+        table! {
+            @parse_columns [$($schema)*], $sql_name, $name ($($pk),+) [] $($body)*
+        }
+    };
+    (
+        @parse_columns [$($schema:ident)*], $sql_name:expr, $name:ident ($($pk:ident),+)
+        [$($parsed:tt)*]
+        #[sql_name = $column_sql_name:expr]
+        $column_name:ident -> $Type:ty,
+        $($rest:tt)*
+    ) => {
+        table! {
+            @parse_columns [$($schema)*], $sql_name, $name ($($pk),+)
+            [$($parsed)* $column_name -> $Type = $column_sql_name,]
+            $($rest)*
+        }
+    };
+    (
+        @parse_columns [$($schema:ident)*], $sql_name:expr, $name:ident ($($pk:ident),+)
+        [$($parsed:tt)*]
+        $column_name:ident -> $Type:ty,
+        $($rest:tt)*
+    ) => {
+        table! {
+            @parse_columns [$($schema)*], $sql_name, $name ($($pk),+)
+            [$($parsed)* $column_name -> $Type = stringify!($column_name),]
+            $($rest)*
+        }
+    };
+    (
+        @parse_columns [$($schema:ident)*], $sql_name:expr, $name:ident ($($pk:ident),+)
+        [$($parsed:tt)*]
+    ) => {
+        table! {
+            @generate [$($schema)*], $sql_name, $name ($($pk),+) {
+                $($parsed)*
+            }
+        }
+    };
+    (@from_clause $out:ident, [], $sql_name:expr) => {
+        $out.push_identifier($sql_name)
+    };
+    (@from_clause $out:ident, [$schema:ident], $sql_name:expr) => {{
+        try!($out.push_identifier(stringify!($schema)));
+        $out.push_sql(".");
+        $out.push_identifier($sql_name)
+    }};
+    (
+        @generate [$($schema:ident)*], $sql_name:expr, $name:ident ($($pk:ident),+) {
+            $($column_name:ident -> $Type:ty = $column_sql_name:expr,)+
         }
     ) => {
         pub mod $name {
@@ -45,7 +127,7 @@ macro_rules! table {
 
             impl QuerySource for table {
                 fn from_clause<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
-                    out.push_identifier(stringify!($name))
+                    table!(@from_clause out, [$($schema)*], $sql_name)
                 }
             }
 
@@ -59,15 +141,15 @@ macro_rules! table {
             }
 
             impl Table for table {
-                type PrimaryKey = columns::$pk;
+                type PrimaryKey = ($(columns::$pk),+);
                 type AllColumns = ($($column_name),+);
 
                 fn name() -> &'static str {
-                    stringify!($name)
+                    $sql_name
                 }
 
                 fn primary_key(&self) -> Self::PrimaryKey {
-                    columns::$pk
+                    ($(columns::$pk),+)
                 }
 
                 fn all_columns() -> Self::AllColumns {
@@ -75,11 +157,16 @@ macro_rules! table {
                 }
             }
 
+            impl $crate::query_source::AppearsInFromClause<table> for table {
+                type Presence = $crate::query_source::Present;
+            }
+
             pub mod columns {
                 use super::table;
-                use $crate::{Table, Column, Expression, SelectableExpression};
+                use $crate::{QuerySource, Table, Column, Expression, SelectableExpression};
                 use $crate::expression::NonAggregate;
                 use $crate::query_builder::{QueryBuilder, BuildQueryResult};
+                use $crate::query_source::{AppearsInFromClause, ColumnTypeIn, SelectableColumnType};
                 use $crate::types::*;
 
                 #[allow(non_camel_case_types, dead_code)]
@@ -90,13 +177,17 @@ macro_rules! table {
                     type SqlType = ();
 
                     fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
-                        try!(out.push_identifier(table::name()));
+                        try!(table.from_clause(out));
                         out.push_sql(".*");
                         Ok(())
                     }
                 }
 
-                impl SelectableExpression<table> for star {}
+                impl<QS> SelectableExpression<QS, ColumnTypeIn<QS, table, ()>> for star where
+                    QS: AppearsInFromClause<table>,
+                    QS::Presence: SelectableColumnType<()>,
+                {
+                }
 
                 $(#[allow(non_camel_case_types, dead_code)]
                 #[derive(Debug, Clone, Copy)]
@@ -106,13 +197,17 @@ macro_rules! table {
                     type SqlType = $Type;
 
                     fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
-                        try!(out.push_identifier(table::name()));
+                        try!(table.from_clause(out));
                         out.push_sql(".");
-                        out.push_identifier(stringify!($column_name))
+                        out.push_identifier($column_sql_name)
                     }
                 }
 
-                impl SelectableExpression<table> for $column_name {}
+                impl<QS> SelectableExpression<QS, ColumnTypeIn<QS, table, $Type>> for $column_name where
+                    QS: AppearsInFromClause<table>,
+                    QS::Presence: SelectableColumnType<$Type>,
+                {
+                }
 
                 impl NonAggregate for $column_name {}
 
@@ -120,7 +215,7 @@ macro_rules! table {
                     type Table = table;
 
                     fn name() -> &'static str {
-                        stringify!($column_name)
+                        $column_sql_name
                     }
                 }
                 )+
@@ -196,6 +291,73 @@ macro_rules! insertable {
 
 #[macro_export]
 macro_rules! changeset {
+    (
+        @skip_none $Struct:ty => $table_mod:ident [$($parsed:tt)*]
+        $field_name:ident -> Option<$Type:ty>,
+        $($rest:tt)*
+    ) => {
+        changeset! {
+            @skip_none $Struct => $table_mod [$($parsed)* optional $field_name $Type,]
+            $($rest)*
+        }
+    };
+    (
+        @skip_none $Struct:ty => $table_mod:ident [$($parsed:tt)*]
+        $field_name:ident -> $Type:ty,
+        $($rest:tt)*
+    ) => {
+        changeset! {
+            @skip_none $Struct => $table_mod [$($parsed)* required $field_name $Type,]
+            $($rest)*
+        }
+    };
+    (
+        @skip_none $Struct:ty => $table_mod:ident [$($kind:ident $field_name:ident $Type:ty,)+]
+    ) => {
+        impl<'a: 'update, 'update> $crate::query_builder::AsChangeset
+            for &'update $Struct
+        {
+            type Changeset = ($(
+                changeset!(@changeset_type $kind 'update, $table_mod::$field_name, $Type)
+            ),+);
+
+            fn as_changeset(self) -> Self::Changeset {
+                use $crate::expression::Expression;
+
+                ($(
+                    changeset!(@change $kind $table_mod::$field_name, &self.$field_name)
+                ),+)
+            }
+        }
+    };
+    (@changeset_type optional $lt:lifetime, $column:path, $Type:ty) => {
+        Option<changeset!(@changeset_type required $lt, $column, $Type)>
+    };
+    (@changeset_type required $lt:lifetime, $column:path, $Type:ty) => {
+        $crate::expression::predicates::Eq<
+            $column,
+            $crate::expression::bound::Bound<
+                <$column as $crate::expression::Expression>::SqlType,
+                &$lt $Type,
+            >,
+        >
+    };
+    (@change optional $column:path, $value:expr) => {
+        $value.as_ref().map(|value| $column.eq(value))
+    };
+    (@change required $column:path, $value:expr) => {
+        $column.eq($value)
+    };
+    (
+        #[skip_none]
+        $Struct:ty => $table_mod:ident {
+            $($fields:tt)+
+        }
+    ) => {
+        changeset! {
+            @skip_none $Struct => $table_mod [] $($fields)+
+        }
+    };
     (
         $Struct:ty => $table_mod:ident {
             $($field_name:ident -> $Type:ty,)+
@@ -247,11 +409,141 @@ macro_rules! joinable_inner {
     }
 }
 
+/// Declares another name for a table, so that it can appear more than once in
+/// the same query. `alias!(employees as managers)` creates a query source named
+/// `managers`, whose columns are accessed with `managers.field(employees::id)`.
+/// To join the alias with other tables, list it as `alias managers` in
+/// `allow_tables_to_appear_in_same_query!`. The alias and its own table are
+/// already allowed together.
+#[macro_export]
+macro_rules! alias {
+    ($table:ident as $alias:ident) => {
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy)]
+        pub struct $alias;
+
+        impl $alias {
+            #[allow(dead_code)]
+            pub fn field<C>(&self, columns: C) -> $crate::query_source::AliasedField<$alias, C> where
+                C: $crate::query_source::AliasableColumns<Table=$table::table>,
+            {
+                $crate::query_source::AliasedField::new(*self, columns)
+            }
+        }
+
+        impl $crate::query_source::AliasSource for $alias {
+            type Table = $table::table;
+
+            fn name() -> &'static str {
+                stringify!($alias)
+            }
+        }
+
+        impl $crate::QuerySource for $alias {
+            fn from_clause<T: $crate::query_builder::QueryBuilder>(&self, out: &mut T)
+                -> $crate::query_builder::BuildQueryResult
+            {
+                try!($table::table.from_clause(out));
+                out.push_sql(" AS ");
+                out.push_identifier(stringify!($alias))
+            }
+        }
+
+        impl $crate::query_source::DefaultSelection for $alias {
+            type SqlType = $table::SqlType;
+            type Selection = $crate::query_source::AliasedField<
+                $alias,
+                <$table::table as $crate::Table>::AllColumns,
+            >;
+
+            fn default_selection(&self) -> Self::Selection {
+                self.field(<$table::table as $crate::Table>::all_columns())
+            }
+        }
+
+        impl $crate::query_builder::AsQuery for $alias {
+            type SqlType = $table::SqlType;
+            type Query = $crate::query_builder::SelectStatement<
+                $table::SqlType,
+                <Self as $crate::query_source::DefaultSelection>::Selection,
+                Self,
+            >;
+
+            fn as_query(self) -> Self::Query {
+                use $crate::query_source::DefaultSelection;
+                $crate::query_builder::SelectStatement::simple(self.default_selection(), self)
+            }
+        }
+
+        impl $crate::JoinOnDsl for $alias {}
+
+        impl $crate::filter_dsl::NotFiltered for $alias {}
+
+        impl $crate::query_source::AppearsInFromClause<$alias> for $alias {
+            type Presence = $crate::query_source::Present;
+        }
+
+        impl $crate::query_source::AppearsInFromClause<$table::table> for $alias {
+            type Presence = $crate::query_source::NotPresent;
+        }
+
+        impl $crate::query_source::AppearsInFromClause<$alias> for $table::table {
+            type Presence = $crate::query_source::NotPresent;
+        }
+    }
+}
+
+/// Allows columns from each of the given tables to be selected from joins
+/// against the others. Every pair of tables which will appear in the same query
+/// must be listed together once. Aliases declared with `alias!` are listed as
+/// `alias name`, e.g. `allow_tables_to_appear_in_same_query!(posts, alias managers)`.
 #[macro_export]
+macro_rules! allow_tables_to_appear_in_same_query {
+    (@parse [$($sources:ty,)*] alias $alias:ident, $($rest:tt)*) => {
+        allow_tables_to_appear_in_same_query!(@parse [$($sources,)* $alias,] $($rest)*);
+    };
+    (@parse [$($sources:ty,)*] $table:ident, $($rest:tt)*) => {
+        allow_tables_to_appear_in_same_query!(@parse [$($sources,)* $table::table,] $($rest)*);
+    };
+    (@parse [$($sources:ty,)*]) => {
+        allow_tables_to_appear_in_same_query!(@pairs $($sources,)*);
+    };
+    (@pairs $left:ty, $($right:ty,)+) => {
+        $(
+            impl $crate::query_source::AppearsInFromClause<$right> for $left {
+                type Presence = $crate::query_source::NotPresent;
+            }
+
+            impl $crate::query_source::AppearsInFromClause<$left> for $right {
+                type Presence = $crate::query_source::NotPresent;
+            }
+        )+
+        allow_tables_to_appear_in_same_query!(@pairs $($right,)+);
+    };
+    (@pairs $last:ty,) => {};
+    ($($source:tt)+) => {
+        allow_tables_to_appear_in_same_query!(@parse [] $($source)+,);
+    };
+}
+
+/// Allows the columns of `parent` to be selected from joins which include
+/// `child`. This is one half of `allow_tables_to_appear_in_same_query!`, which
+/// should be used instead, and the two can't be combined for the same tables.
+#[macro_export]
+#[deprecated(note = "use allow_tables_to_appear_in_same_query! instead")]
 macro_rules! select_column_workaround {
     ($parent:ident -> $child:ident ($($column_name:ident),+)) => {
-        $(select_column_inner!($parent -> $child $column_name);)+
-        select_column_inner!($parent -> $child star);
+        impl $crate::query_source::AppearsInFromClause<$parent::table> for $child::table {
+            type Presence = $crate::query_source::NotPresent;
+        }
+    }
+}
+
+#[macro_export]
+#[deprecated(note = "use allow_tables_to_appear_in_same_query! instead")]
+macro_rules! select_column_inner {
+    ($parent:ident -> $child:ident $column_name:ident) => {
+        compile_error!("select_column_inner! has been removed, use allow_tables_to_appear_in_same_query! instead");
     }
 }
 
@@ -299,33 +591,151 @@ macro_rules! one_to_many {
     };
 }
 
+/// Declares a SQL type for a Postgres enum created with `CREATE TYPE ... AS
+/// ENUM`, along with a Rust enum whose variants are sent and received as the
+/// enum's labels. `sql_name` is the name of the type in the database. The OID
+/// of a user defined type differs between databases, so it is looked up by
+/// `sql_name` on each connection.
+///
+/// ```ignore
+/// sql_enum! {
+///     #[sql_name = "mood"]
+///     MoodType -> Mood {
+///         Sad = "sad",
+///         Ok = "ok",
+///         Happy = "happy",
+///     }
+/// }
+/// ```
 #[macro_export]
-macro_rules! select_column_inner {
-    ($parent:ident -> $child:ident $column_name:ident) => {
-        impl $crate::expression::SelectableExpression<
-            $crate::query_source::InnerJoinSource<$child::table, $parent::table>,
-        > for $parent::$column_name
-        {
+macro_rules! sql_enum {
+    (
+        #[sql_name = $sql_name:expr]
+        $SqlType:ident -> $Enum:ident {
+            $($Variant:ident = $label:expr,)+
         }
+    ) => {
+        #[derive(Clone, Copy)]
+        pub struct $SqlType;
 
-        impl $crate::expression::SelectableExpression<
-            $crate::query_source::InnerJoinSource<$parent::table, $child::table>,
-        > for $parent::$column_name
-        {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $Enum {
+            $($Variant),+
         }
 
-        impl $crate::expression::SelectableExpression<
-            $crate::query_source::LeftOuterJoinSource<$child::table, $parent::table>,
-            $crate::types::Nullable<
-                <$parent::$column_name as $crate::Expression>::SqlType>,
-        > for $parent::$column_name
-        {
+        impl $crate::types::NativeSqlType for $SqlType {
+            fn oid() -> u32 {
+                0
+            }
+
+            fn type_name() -> Option<$crate::types::TypeName> {
+                Some($crate::types::TypeName::new($sql_name))
+            }
         }
 
-        impl $crate::expression::SelectableExpression<
-            $crate::query_source::LeftOuterJoinSource<$parent::table, $child::table>,
-        > for $parent::$column_name
-        {
+        impl $crate::types::SqlOrd for $SqlType {}
+
+        impl $crate::types::FromSql<$SqlType> for $Enum {
+            fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<::std::error::Error>> {
+                let label: String = try!($crate::types::FromSql::<$crate::types::Text>::from_sql(bytes));
+                $(if label == $label {
+                    return Ok($Enum::$Variant);
+                })+
+                Err(format!("Unrecognized label {:?} for enum {}", label, $sql_name).into())
+            }
         }
-    }
+
+        impl $crate::types::ToSql<$SqlType> for $Enum {
+            fn to_sql<W: ::std::io::Write>(&self, out: &mut W)
+                -> Result<$crate::types::IsNull, Box<::std::error::Error>>
+            {
+                let label: &str = match *self {
+                    $($Enum::$Variant => $label),+
+                };
+                try!(out.write_all(label.as_bytes()));
+                Ok($crate::types::IsNull::No)
+            }
+        }
+
+        impl $crate::types::ToSql<$crate::types::Nullable<$SqlType>> for $Enum {
+            fn to_sql<W: ::std::io::Write>(&self, out: &mut W)
+                -> Result<$crate::types::IsNull, Box<::std::error::Error>>
+            {
+                $crate::types::ToSql::<$SqlType>::to_sql(self, out)
+            }
+        }
+
+        impl $crate::Queriable<$SqlType> for $Enum {
+            type Row = Self;
+
+            fn build(row: Self::Row) -> Self {
+                row
+            }
+        }
+
+        sql_enum!(@as_expression $SqlType, $Enum);
+        sql_enum!(@as_expression $crate::types::Nullable<$SqlType>, $Enum);
+    };
+    (@as_expression $SqlType:ty, $Enum:ident) => {
+        impl $crate::expression::AsExpression<$SqlType> for $Enum {
+            type Expression = $crate::expression::bound::Bound<$SqlType, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                $crate::expression::bound::Bound::new(self)
+            }
+        }
+
+        impl<'a> $crate::expression::AsExpression<$SqlType> for &'a $Enum {
+            type Expression = $crate::expression::bound::Bound<$SqlType, Self>;
+
+            fn as_expression(self) -> Self::Expression {
+                $crate::expression::bound::Bound::new(self)
+            }
+        }
+    };
+}
+
+/// Declares a SQL type for a Postgres composite type created with `CREATE TYPE
+/// ... AS (...)`. The fields must be listed in the order they were declared,
+/// and their SQL types must be in scope. Values are sent and received as
+/// tuples of the fields' types. Like `sql_enum!`, the OID of the type is looked
+/// up by `sql_name` on each connection.
+///
+/// ```ignore
+/// sql_composite! {
+///     #[sql_name = "address"]
+///     AddressType {
+///         street -> Text,
+///         city -> Text,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! sql_composite {
+    (
+        #[sql_name = $sql_name:expr]
+        $SqlType:ident {
+            $($field_name:ident -> $FieldType:ty,)+
+        }
+    ) => {
+        #[derive(Clone, Copy)]
+        pub struct $SqlType;
+
+        impl $crate::types::NativeSqlType for $SqlType {
+            fn oid() -> u32 {
+                0
+            }
+
+            fn type_name() -> Option<$crate::types::TypeName> {
+                Some($crate::types::TypeName::new($sql_name))
+            }
+        }
+
+        impl $crate::types::CompositeType for $SqlType {
+            type Fields = ($($FieldType),+);
+        }
+
+        impl $crate::types::NamedCompositeType for $SqlType {
+        }
+    };
 }
@@ -17,7 +17,7 @@ pub trait Insertable<T: Table> {
 pub trait InsertableColumns<T: Table> {
     type SqlType: NativeSqlType;
 
-    fn names(&self) -> String;
+    fn names_to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult;
 }
 
 impl<'a, T, U> Insertable<T> for &'a [U] where
@@ -88,7 +88,7 @@ impl<'a, T, U> Expression for InsertValues<'a, T, U> where
 impl<C: Column<Table=T>, T: Table> InsertableColumns<T> for C {
     type SqlType = <Self as Expression>::SqlType;
 
-    fn names(&self) -> String {
-        Self::name().to_string()
+    fn names_to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        out.push_identifier(Self::name())
     }
 }
@@ -0,0 +1,51 @@
+use expression::Expression;
+use query_builder::{Query, AsQuery, QueryFragment, QueryBuilder, BuildQueryResult};
+use query_source::{QuerySource, Table};
+use super::update_statement::UpdateTarget;
+
+/// Deletes the rows of `source`, which can be anything `update` accepts.
+/// Tables joined to the target are rendered with `DELETE ... USING`.
+pub fn delete<T: UpdateTarget>(source: T) -> DeleteStatement<T> {
+    DeleteStatement(source)
+}
+
+pub struct DeleteStatement<T>(T);
+
+impl<T: UpdateTarget> QueryFragment for DeleteStatement<T> {
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        out.push_sql("DELETE FROM ");
+        try!(self.0.table().from_clause(out));
+        if self.0.has_other_tables() {
+            out.push_sql(" USING ");
+            try!(self.0.other_tables(out));
+        }
+        if self.0.has_predicates() {
+            out.push_sql(" WHERE ");
+            try!(self.0.predicates(out));
+        }
+        Ok(())
+    }
+}
+
+impl<T: UpdateTarget> AsQuery for DeleteStatement<T> {
+    type SqlType = <Self::Query as Query>::SqlType;
+    type Query = DeleteQuery<T>;
+
+    fn as_query(self) -> Self::Query {
+        DeleteQuery(self)
+    }
+}
+
+pub struct DeleteQuery<T>(DeleteStatement<T>);
+
+impl<T: UpdateTarget> QueryFragment for DeleteQuery<T> {
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        try!(self.0.to_sql(out));
+        out.push_sql(" RETURNING ");
+        Expression::to_sql(&T::Table::all_columns(), out)
+    }
+}
+
+impl<T: UpdateTarget> Query for DeleteQuery<T> {
+    type SqlType = <<T::Table as Table>::AllColumns as Expression>::SqlType;
+}
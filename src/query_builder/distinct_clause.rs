@@ -0,0 +1,73 @@
+use expression::Expression;
+use expression::ordering::Desc;
+use super::{QueryFragment, QueryBuilder, BuildQueryResult};
+use super::order_clause::{NoOrderClause, OrderClause};
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoDistinctClause;
+
+impl QueryFragment for NoDistinctClause {
+    fn to_sql<T: QueryBuilder>(&self, _out: &mut T) -> BuildQueryResult {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DistinctClause;
+
+impl QueryFragment for DistinctClause {
+    fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        out.push_sql("DISTINCT ");
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DistinctOnClause<Expr>(pub Expr);
+
+impl<Expr: Expression> QueryFragment for DistinctOnClause<Expr> {
+    fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        out.push_sql("DISTINCT ON (");
+        try!(self.0.to_sql(out));
+        out.push_sql(") ");
+        Ok(())
+    }
+}
+
+/// PG requires the expressions given to `DISTINCT ON` to match the leftmost
+/// expressions of `ORDER BY`. This trait is implemented for the order clauses
+/// which can be used alongside a given distinct clause.
+pub trait ValidOrderingForDistinct<Distinct> {
+}
+
+impl<O> ValidOrderingForDistinct<NoDistinctClause> for O {}
+impl<O> ValidOrderingForDistinct<DistinctClause> for O {}
+
+impl<T> ValidOrderingForDistinct<DistinctOnClause<T>> for NoOrderClause {}
+impl<T> ValidOrderingForDistinct<DistinctOnClause<T>> for OrderClause<T> {}
+impl<T> ValidOrderingForDistinct<DistinctOnClause<T>> for OrderClause<Desc<T>> {}
+
+macro_rules! valid_tuple_orderings_for_distinct {
+    ($($Rest:ident),+) => {
+        impl<T, $($Rest),+> ValidOrderingForDistinct<DistinctOnClause<T>>
+            for OrderClause<(T, $($Rest),+)> {}
+        impl<T, $($Rest),+> ValidOrderingForDistinct<DistinctOnClause<T>>
+            for OrderClause<(Desc<T>, $($Rest),+)> {}
+    };
+}
+
+valid_tuple_orderings_for_distinct!(B);
+valid_tuple_orderings_for_distinct!(B, C);
+valid_tuple_orderings_for_distinct!(B, C, D);
+valid_tuple_orderings_for_distinct!(B, C, D, E);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F, G);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F, G, H);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F, G, H, I);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F, G, H, I, J);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F, G, H, I, J, K);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F, G, H, I, J, K, L);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F, G, H, I, J, K, L, M);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F, G, H, I, J, K, L, M, N);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F, G, H, I, J, K, L, M, N, O);
+valid_tuple_orderings_for_distinct!(B, C, D, E, F, G, H, I, J, K, L, M, N, O, P);
@@ -0,0 +1,94 @@
+use expression::Expression;
+use persistable::InsertableColumns;
+use query_builder::{Query, AsQuery, QueryFragment, QueryBuilder, BuildQueryResult};
+use query_source::Table;
+
+/// Begins an `INSERT INTO ... SELECT` into `target`. Rows are inserted into
+/// every column of the table unless a narrower list is given with `columns`.
+pub fn insert_into<T: Table>(target: T) -> IncompleteInsertStatement<T, T::AllColumns> {
+    IncompleteInsertStatement {
+        target: target,
+        columns: T::all_columns(),
+    }
+}
+
+pub struct IncompleteInsertStatement<T, C> {
+    target: T,
+    columns: C,
+}
+
+impl<T: Table, C> IncompleteInsertStatement<T, C> {
+    pub fn columns<U>(self, columns: U) -> IncompleteInsertStatement<T, U> where
+        U: InsertableColumns<T>,
+    {
+        IncompleteInsertStatement {
+            target: self.target,
+            columns: columns,
+        }
+    }
+
+    /// The rows returned by `query` are inserted into the columns of this
+    /// statement, so its SQL type must match theirs.
+    pub fn select_from<Q>(self, query: Q) -> InsertStatement<T, C, Q::Query> where
+        C: InsertableColumns<T>,
+        Q: AsQuery<SqlType=C::SqlType>,
+    {
+        InsertStatement {
+            target: self.target,
+            columns: self.columns,
+            query: query.as_query(),
+        }
+    }
+}
+
+pub struct InsertStatement<T, C, Q> {
+    target: T,
+    columns: C,
+    query: Q,
+}
+
+impl<T, C, Q> QueryFragment for InsertStatement<T, C, Q> where
+    T: Table,
+    C: InsertableColumns<T>,
+    Q: Query<SqlType=C::SqlType>,
+{
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        out.push_sql("INSERT INTO ");
+        try!(self.target.from_clause(out));
+        out.push_sql(" (");
+        try!(self.columns.names_to_sql(out));
+        out.push_sql(") ");
+        self.query.to_sql(out)
+    }
+}
+
+impl<T, C, Q> AsQuery for InsertStatement<T, C, Q> where
+    InsertQuery<T, C, Q>: Query,
+{
+    type SqlType = <Self::Query as Query>::SqlType;
+    type Query = InsertQuery<T, C, Q>;
+
+    fn as_query(self) -> Self::Query {
+        InsertQuery(self)
+    }
+}
+
+pub struct InsertQuery<T, C, Q>(InsertStatement<T, C, Q>);
+
+impl<T, C, Q> QueryFragment for InsertQuery<T, C, Q> where
+    T: Table,
+    InsertStatement<T, C, Q>: QueryFragment,
+{
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        try!(self.0.to_sql(out));
+        out.push_sql(" RETURNING ");
+        Expression::to_sql(&T::all_columns(), out)
+    }
+}
+
+impl<T, C, Q> Query for InsertQuery<T, C, Q> where
+    T: Table,
+    InsertQuery<T, C, Q>: QueryFragment,
+{
+    type SqlType = <T::AllColumns as Expression>::SqlType;
+}
@@ -0,0 +1,83 @@
+use expression::NonAggregate;
+use expression::sql_literal::SqlLiteral;
+use super::{QueryFragment, QueryBuilder, BuildQueryResult};
+use super::distinct_clause::NoDistinctClause;
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoLockingClause;
+
+impl QueryFragment for NoLockingClause {
+    fn to_sql<T: QueryBuilder>(&self, _out: &mut T) -> BuildQueryResult {
+        Ok(())
+    }
+}
+
+/// Implemented only by `NoLockingClause`, so that a query which already has a
+/// locking clause can't be given a second one.
+pub trait NotLocked {
+}
+
+impl NotLocked for NoLockingClause {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LockingClause<Mode, Modifier = NoModifier> {
+    pub mode: Mode,
+    pub modifier: Modifier,
+}
+
+impl<Mode, Modifier> QueryFragment for LockingClause<Mode, Modifier> where
+    Mode: QueryFragment,
+    Modifier: QueryFragment,
+{
+    fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        try!(self.mode.to_sql(out));
+        self.modifier.to_sql(out)
+    }
+}
+
+macro_rules! simple_clause {
+    ($name:ident, $sql:expr) => {
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+
+        impl QueryFragment for $name {
+            fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+                out.push_sql($sql);
+                Ok(())
+            }
+        }
+    }
+}
+
+simple_clause!(ForUpdate, " FOR UPDATE");
+simple_clause!(ForNoKeyUpdate, " FOR NO KEY UPDATE");
+simple_clause!(ForShare, " FOR SHARE");
+simple_clause!(ForKeyShare, " FOR KEY SHARE");
+
+simple_clause!(NoModifier, "");
+simple_clause!(SkipLocked, " SKIP LOCKED");
+simple_clause!(NoWait, " NOWAIT");
+
+/// PG does not allow row level locks on queries which use `DISTINCT`, or which
+/// select aggregate expressions. This trait is implemented for the locking
+/// clauses which are valid for a given select and distinct clause.
+pub trait ValidLockingClause<Select, Distinct> {
+}
+
+impl<S, D> ValidLockingClause<S, D> for NoLockingClause {}
+
+impl<S, Mode, Modifier> ValidLockingClause<S, NoDistinctClause>
+    for LockingClause<Mode, Modifier> where
+    S: LockableSelection,
+{
+}
+
+/// Selections which can be locked. Whether an expression built with the DSL is
+/// an aggregate is known, but raw SQL from `select_sql` can't be checked, so
+/// it is left to the database to reject.
+pub trait LockableSelection {
+}
+
+impl<S: NonAggregate> LockableSelection for S {}
+
+impl<ST> LockableSelection for SqlLiteral<ST> {}
@@ -1,17 +1,26 @@
 pub mod pg;
 
+pub mod delete_statement;
+mod distinct_clause;
+mod insert_statement;
 mod limit_clause;
+pub mod locking_clause;
 mod order_clause;
 mod select_statement;
+pub mod set_operation;
 mod where_clause;
 pub mod update_statement;
+pub mod with_clause;
 
-pub use self::select_statement::SelectStatement;
-pub use self::update_statement::{update, IncompleteUpdateStatement, AsChangeset, Changeset, UpdateTarget};
+pub use self::delete_statement::delete;
+pub use self::insert_statement::{insert_into, IncompleteInsertStatement, InsertStatement};
+pub use self::select_statement::{SelectStatement, BoxedSelectStatement};
+pub use self::update_statement::{update, IncompleteUpdateStatement, AsChangeset, Changeset, SelectableChangeset, UpdateTarget};
+pub use self::with_clause::{with, with_recursive};
 
 use expression::Expression;
 use std::error::Error;
-use types::NativeSqlType;
+use types::{NativeSqlType, TypeName};
 
 pub type Binds = Vec<Option<Vec<u8>>>;
 pub type BuildQueryResult = Result<(), Box<Error>>;
@@ -19,7 +28,38 @@ pub type BuildQueryResult = Result<(), Box<Error>>;
 pub trait QueryBuilder {
     fn push_sql(&mut self, sql: &str);
     fn push_identifier(&mut self, identifier: &str) -> BuildQueryResult;
-    fn push_bound_value<T: NativeSqlType>(&mut self, binds: Option<Vec<u8>>);
+    fn push_bound_value_with_oid(&mut self, oid: u32, binds: Option<Vec<u8>>);
+    fn push_bound_value_with_type_name(&mut self, type_name: TypeName, binds: Option<Vec<u8>>)
+        -> BuildQueryResult;
+
+    fn push_bound_value<T: NativeSqlType>(&mut self, binds: Option<Vec<u8>>) -> BuildQueryResult where
+        Self: Sized,
+    {
+        match T::type_name() {
+            Some(type_name) => self.push_bound_value_with_type_name(type_name, binds),
+            None => Ok(self.push_bound_value_with_oid(T::oid(), binds)),
+        }
+    }
+}
+
+impl<'a, 'b> QueryBuilder for &'a mut (QueryBuilder + 'b) {
+    fn push_sql(&mut self, sql: &str) {
+        (**self).push_sql(sql)
+    }
+
+    fn push_identifier(&mut self, identifier: &str) -> BuildQueryResult {
+        (**self).push_identifier(identifier)
+    }
+
+    fn push_bound_value_with_oid(&mut self, oid: u32, binds: Option<Vec<u8>>) {
+        (**self).push_bound_value_with_oid(oid, binds)
+    }
+
+    fn push_bound_value_with_type_name(&mut self, type_name: TypeName, binds: Option<Vec<u8>>)
+        -> BuildQueryResult
+    {
+        (**self).push_bound_value_with_type_name(type_name, binds)
+    }
 }
 
 pub trait Query: QueryFragment {
@@ -36,6 +76,18 @@ impl<T: Expression> QueryFragment for T {
     }
 }
 
+/// A `QueryFragment` which can be used as a trait object, for queries whose
+/// clauses are not known until runtime.
+pub trait BoxableQueryFragment {
+    fn to_boxed_sql(&self, out: &mut QueryBuilder) -> BuildQueryResult;
+}
+
+impl<T: QueryFragment> BoxableQueryFragment for T {
+    fn to_boxed_sql(&self, mut out: &mut QueryBuilder) -> BuildQueryResult {
+        self.to_sql(&mut out)
+    }
+}
+
 pub trait AsQuery {
     type SqlType: NativeSqlType;
     type Query: Query<SqlType=Self::SqlType>;
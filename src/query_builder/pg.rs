@@ -1,6 +1,6 @@
 use connection::Connection;
 use super::{QueryBuilder, Binds, BuildQueryResult};
-use types::NativeSqlType;
+use types::TypeName;
 
 pub struct PgQueryBuilder<'a> {
     conn: &'a Connection,
@@ -32,11 +32,18 @@ impl<'a> QueryBuilder for PgQueryBuilder<'a> {
         Ok(self.push_sql(&escaped_identifier))
     }
 
-    fn push_bound_value<T: NativeSqlType>(&mut self, bind: Option<Vec<u8>>) {
+    fn push_bound_value_with_oid(&mut self, oid: u32, bind: Option<Vec<u8>>) {
         self.bind_idx += 1;
         let sql = format!("${}", self.bind_idx);
         self.push_sql(&sql);
         self.binds.push(bind);
-        self.bind_types.push(T::oid());
+        self.bind_types.push(oid);
+    }
+
+    fn push_bound_value_with_type_name(&mut self, type_name: TypeName, bind: Option<Vec<u8>>)
+        -> BuildQueryResult
+    {
+        let oid = try!(self.conn.type_oid(type_name));
+        Ok(self.push_bound_value_with_oid(oid, bind))
     }
 }
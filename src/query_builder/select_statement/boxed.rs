@@ -0,0 +1,114 @@
+use expression::*;
+use query_builder::*;
+use query_builder::limit_clause::LimitClause;
+use query_builder::order_clause::OrderClause;
+use query_builder::where_clause::{BoxedWhereClause, WhereAnd};
+use query_dsl::*;
+use query_source::QuerySource;
+use std::marker::PhantomData;
+use types::{self, Bool, NativeSqlType};
+
+/// A select statement whose clauses are stored as trait objects, created with
+/// `into_boxed`. Filtering, ordering or limiting it does not change its type,
+/// so it can be built up conditionally. Only the result type and the query
+/// source appear in the type.
+pub struct BoxedSelectStatement<'a, ST, QS> {
+    select: Box<BoxableQueryFragment + 'a>,
+    from: QS,
+    where_clause: BoxedWhereClause<'a>,
+    order: Box<BoxableQueryFragment + 'a>,
+    limit: Box<BoxableQueryFragment + 'a>,
+    _marker: PhantomData<ST>,
+}
+
+impl<'a, ST, QS> BoxedSelectStatement<'a, ST, QS> {
+    pub fn new(
+        select: Box<BoxableQueryFragment + 'a>,
+        from: QS,
+        where_clause: BoxedWhereClause<'a>,
+        order: Box<BoxableQueryFragment + 'a>,
+        limit: Box<BoxableQueryFragment + 'a>,
+    ) -> Self {
+        BoxedSelectStatement {
+            select: select,
+            from: from,
+            where_clause: where_clause,
+            order: order,
+            limit: limit,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, ST, QS> Query for BoxedSelectStatement<'a, ST, QS> where
+    ST: NativeSqlType,
+    QS: QuerySource,
+{
+    type SqlType = ST;
+}
+
+impl<'a, ST, QS> Expression for BoxedSelectStatement<'a, ST, QS> where
+    ST: NativeSqlType,
+    QS: QuerySource,
+{
+    type SqlType = types::Array<ST>;
+
+    fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        out.push_sql("SELECT ");
+        try!(self.select.to_boxed_sql(out));
+        out.push_sql(" FROM ");
+        try!(self.from.from_clause(out));
+        try!(self.where_clause.to_sql(out));
+        try!(self.order.to_boxed_sql(out));
+        self.limit.to_boxed_sql(out)
+    }
+}
+
+impl<'a, ST, QS, QS2> SelectableExpression<QS2> for BoxedSelectStatement<'a, ST, QS> where
+    BoxedSelectStatement<'a, ST, QS>: Expression,
+{
+}
+
+impl<'a, ST, QS> NonAggregate for BoxedSelectStatement<'a, ST, QS> where
+    BoxedSelectStatement<'a, ST, QS>: Expression,
+{
+}
+
+impl<'a, ST, QS, Predicate> FilterDsl<Predicate> for BoxedSelectStatement<'a, ST, QS> where
+    ST: NativeSqlType,
+    QS: QuerySource,
+    Predicate: SelectableExpression<QS, SqlType=Bool> + NonAggregate + 'a,
+{
+    type Output = Self;
+
+    fn filter(mut self, predicate: Predicate) -> Self::Output {
+        self.where_clause = self.where_clause.and(predicate);
+        self
+    }
+}
+
+impl<'a, ST, QS, Expr> OrderDsl<Expr> for BoxedSelectStatement<'a, ST, QS> where
+    ST: NativeSqlType,
+    QS: QuerySource,
+    Expr: SelectableExpression<QS> + 'a,
+{
+    type Output = Self;
+
+    fn order(mut self, expr: Expr) -> Self::Output {
+        self.order = Box::new(OrderClause(expr));
+        self
+    }
+}
+
+impl<'a, ST, QS> LimitDsl for BoxedSelectStatement<'a, ST, QS> where
+    ST: NativeSqlType,
+    QS: QuerySource,
+{
+    type Output = Self;
+
+    fn limit(mut self, limit: i64) -> Self::Output {
+        let limit = AsExpression::<types::BigInt>::as_expression(limit);
+        self.limit = Box::new(LimitClause(limit));
+        self
+    }
+}
@@ -1,65 +1,142 @@
 use expression::*;
 use query_builder::*;
+use super::BoxedSelectStatement;
+use query_builder::distinct_clause::*;
 use query_builder::limit_clause::*;
+use query_builder::locking_clause::*;
 use query_builder::order_clause::*;
 use query_builder::where_clause::*;
 use query_dsl::*;
+use query_source::QuerySource;
 use types::{self, Bool, NativeSqlType};
 
-impl<ST, S, F, W, O, L, Selection, Type> SelectDsl<Selection, Type>
-    for SelectStatement<ST, S, F, W, O, L> where
+impl<ST, S, F, W, O, L, D, Lk, Selection, Type> SelectDsl<Selection, Type>
+    for SelectStatement<ST, S, F, W, O, L, D, Lk> where
     Selection: Expression,
-    SelectStatement<Type, Selection, F, W, O, L>: Query<SqlType=Type>,
+    SelectStatement<Type, Selection, F, W, O, L, D, Lk>: Query<SqlType=Type>,
     Type: NativeSqlType,
 {
-    type Output = SelectStatement<Type, Selection, F, W, O, L>;
+    type Output = SelectStatement<Type, Selection, F, W, O, L, D, Lk>;
 
     fn select(self, selection: Selection) -> Self::Output {
         SelectStatement::new(selection, self.from, self.where_clause, self.order,
-            self.limit)
+            self.limit, self.distinct, self.lock)
     }
 }
 
-impl<ST, S, F, W, O, L, Predicate> FilterDsl<Predicate>
-    for SelectStatement<ST, S, F, W, O, L> where
+impl<ST, S, F, W, O, L, D, Lk, Predicate> FilterDsl<Predicate>
+    for SelectStatement<ST, S, F, W, O, L, D, Lk> where
     Predicate: SelectableExpression<F, SqlType=Bool> + NonAggregate,
     W: WhereAnd<Predicate>,
-    SelectStatement<ST, S, F, W::Output, O, L>: Query,
+    SelectStatement<ST, S, F, W::Output, O, L, D, Lk>: Query,
 {
-    type Output = SelectStatement<ST, S, F, W::Output, O, L>;
+    type Output = SelectStatement<ST, S, F, W::Output, O, L, D, Lk>;
 
     fn filter(self, predicate: Predicate) -> Self::Output {
         SelectStatement::new(self.select, self.from, self.where_clause.and(predicate),
-            self.order, self.limit)
+            self.order, self.limit, self.distinct, self.lock)
     }
 }
 
-impl<ST, S, F, W, O, L, Expr> OrderDsl<Expr>
-    for SelectStatement<ST, S, F, W, O, L> where
+impl<ST, S, F, W, O, L, D, Lk, Expr> OrderDsl<Expr>
+    for SelectStatement<ST, S, F, W, O, L, D, Lk> where
     ST: NativeSqlType,
     Expr: SelectableExpression<F>,
-    SelectStatement<ST, S, F, W, OrderClause<Expr>, L>: Query<SqlType=ST>,
+    SelectStatement<ST, S, F, W, OrderClause<Expr>, L, D, Lk>: Query<SqlType=ST>,
 {
-    type Output = SelectStatement<ST, S, F, W, OrderClause<Expr>, L>;
+    type Output = SelectStatement<ST, S, F, W, OrderClause<Expr>, L, D, Lk>;
 
     fn order(self, expr: Expr) -> Self::Output {
         let order = OrderClause(expr);
         SelectStatement::new(self.select, self.from, self.where_clause, order,
-            self.limit)
+            self.limit, self.distinct, self.lock)
     }
 }
 
 type Limit = <i64 as AsExpression<types::BigInt>>::Expression;
 
-impl<ST, S, F, W, O, L> LimitDsl for SelectStatement<ST, S, F, W, O, L> where
+impl<ST, S, F, W, O, L, D, Lk> LimitDsl for SelectStatement<ST, S, F, W, O, L, D, Lk> where
     ST: NativeSqlType,
-    SelectStatement<ST, S, F, W, O, LimitClause<Limit>>: Query<SqlType=ST>,
+    SelectStatement<ST, S, F, W, O, LimitClause<Limit>, D, Lk>: Query<SqlType=ST>,
 {
-    type Output = SelectStatement<ST, S, F, W, O, LimitClause<Limit>>;
+    type Output = SelectStatement<ST, S, F, W, O, LimitClause<Limit>, D, Lk>;
 
     fn limit(self, limit: i64) -> Self::Output {
         let limit_clause = LimitClause(AsExpression::<types::BigInt>::as_expression(limit));
         SelectStatement::new(self.select, self.from, self.where_clause,
-            self.order, limit_clause)
+            self.order, limit_clause, self.distinct, self.lock)
+    }
+}
+
+impl<ST, S, F, W, O, L, D, Lk> DistinctDsl for SelectStatement<ST, S, F, W, O, L, D, Lk> where
+    ST: NativeSqlType,
+    SelectStatement<ST, S, F, W, O, L, DistinctClause, Lk>: Query<SqlType=ST>,
+{
+    type Output = SelectStatement<ST, S, F, W, O, L, DistinctClause, Lk>;
+
+    fn distinct(self) -> Self::Output {
+        SelectStatement::new(self.select, self.from, self.where_clause,
+            self.order, self.limit, DistinctClause, self.lock)
+    }
+}
+
+impl<ST, S, F, W, O, L, D, Lk, Expr> DistinctOnDsl<Expr>
+    for SelectStatement<ST, S, F, W, O, L, D, Lk> where
+    ST: NativeSqlType,
+    Expr: SelectableExpression<F> + NonAggregate,
+    SelectStatement<ST, S, F, W, O, L, DistinctOnClause<Expr>, Lk>: Query<SqlType=ST>,
+{
+    type Output = SelectStatement<ST, S, F, W, O, L, DistinctOnClause<Expr>, Lk>;
+
+    fn distinct_on(self, expr: Expr) -> Self::Output {
+        SelectStatement::new(self.select, self.from, self.where_clause,
+            self.order, self.limit, DistinctOnClause(expr), self.lock)
+    }
+}
+
+impl<ST, S, F, W, O, L, D, Lk, Mode> LockingDsl<Mode>
+    for SelectStatement<ST, S, F, W, O, L, D, Lk> where
+    ST: NativeSqlType,
+    Lk: NotLocked,
+    SelectStatement<ST, S, F, W, O, L, D, LockingClause<Mode>>: Query<SqlType=ST>,
+{
+    type Output = SelectStatement<ST, S, F, W, O, L, D, LockingClause<Mode>>;
+
+    fn with_lock(self, mode: Mode) -> Self::Output {
+        let lock = LockingClause { mode: mode, modifier: NoModifier };
+        SelectStatement::new(self.select, self.from, self.where_clause,
+            self.order, self.limit, self.distinct, lock)
+    }
+}
+
+impl<ST, S, F, W, O, L, D, Mode, Modifier> ModifyLockDsl<Modifier>
+    for SelectStatement<ST, S, F, W, O, L, D, LockingClause<Mode>> where
+    ST: NativeSqlType,
+    SelectStatement<ST, S, F, W, O, L, D, LockingClause<Mode, Modifier>>: Query<SqlType=ST>,
+{
+    type Output = SelectStatement<ST, S, F, W, O, L, D, LockingClause<Mode, Modifier>>;
+
+    fn modify_lock(self, modifier: Modifier) -> Self::Output {
+        let lock = LockingClause { mode: self.lock.mode, modifier: modifier };
+        SelectStatement::new(self.select, self.from, self.where_clause,
+            self.order, self.limit, self.distinct, lock)
+    }
+}
+
+impl<'a, ST, S, F, W, O, L> BoxedDsl<'a> for SelectStatement<ST, S, F, W, O, L> where
+    ST: NativeSqlType,
+    S: QueryFragment + 'a,
+    F: QuerySource,
+    W: IntoBoxedWhereClause<'a>,
+    O: QueryFragment + 'a,
+    L: QueryFragment + 'a,
+    SelectStatement<ST, S, F, W, O, L>: Query<SqlType=ST>,
+{
+    type Output = BoxedSelectStatement<'a, ST, F>;
+
+    fn into_boxed(self) -> Self::Output {
+        BoxedSelectStatement::new(Box::new(self.select), self.from,
+            self.where_clause.into_boxed_where_clause(), Box::new(self.order),
+            Box::new(self.limit))
     }
 }
@@ -1,9 +1,14 @@
+mod boxed;
 mod dsl_impls;
 
+pub use self::boxed::BoxedSelectStatement;
+
 use expression::*;
 use query_source::QuerySource;
 use std::marker::PhantomData;
 use super::{Query, QueryBuilder, QueryFragment, BuildQueryResult};
+use super::distinct_clause::{NoDistinctClause, ValidOrderingForDistinct};
+use super::locking_clause::{NoLockingClause, ValidLockingClause};
 use super::where_clause::NoWhereClause;
 use super::order_clause::NoOrderClause;
 use super::limit_clause::NoLimitClause;
@@ -17,23 +22,37 @@ pub struct SelectStatement<
     Where = NoWhereClause,
     Order = NoOrderClause,
     Limit = NoLimitClause,
+    Distinct = NoDistinctClause,
+    Lock = NoLockingClause,
 > {
     select: Select,
     from: From,
     where_clause: Where,
     order: Order,
     limit: Limit,
+    distinct: Distinct,
+    lock: Lock,
     _marker: PhantomData<SqlType>,
 }
 
-impl<ST, S, F, W, O, L> SelectStatement<ST, S, F, W, O, L> {
-    pub fn new(select: S, from: F, where_clause: W, order: O, limit: L) -> Self {
+impl<ST, S, F, W, O, L, D, Lk> SelectStatement<ST, S, F, W, O, L, D, Lk> {
+    pub fn new(
+        select: S,
+        from: F,
+        where_clause: W,
+        order: O,
+        limit: L,
+        distinct: D,
+        lock: Lk,
+    ) -> Self {
         SelectStatement {
             select: select,
             from: from,
             where_clause: where_clause,
             order: order,
             limit: limit,
+            distinct: distinct,
+            lock: lock,
             _marker: PhantomData,
         }
     }
@@ -41,44 +60,49 @@ impl<ST, S, F, W, O, L> SelectStatement<ST, S, F, W, O, L> {
 
 impl<ST, S, F> SelectStatement<ST, S, F> {
     pub fn simple(select: S, from: F) -> Self {
-        SelectStatement::new(select, from, NoWhereClause, NoOrderClause, NoLimitClause)
+        SelectStatement::new(select, from, NoWhereClause, NoOrderClause, NoLimitClause,
+            NoDistinctClause, NoLockingClause)
     }
 }
 
-impl<ST, S, F, W, O, L> Query for SelectStatement<ST, S, F, W, O, L> where
+impl<ST, S, F, W, O, L, D, Lk> Query for SelectStatement<ST, S, F, W, O, L, D, Lk> where
     ST: NativeSqlType,
-    SelectStatement<ST, S, F, W, O, L>: QueryFragment
+    SelectStatement<ST, S, F, W, O, L, D, Lk>: QueryFragment
 {
     type SqlType = ST;
 }
 
-impl<ST, S, F, W, O, L> Expression for SelectStatement<ST, S, F, W, O, L> where
+impl<ST, S, F, W, O, L, D, Lk> Expression for SelectStatement<ST, S, F, W, O, L, D, Lk> where
     ST: NativeSqlType,
     F: QuerySource,
     S: SelectableExpression<F, ST>,
     W: QueryFragment,
-    O: QueryFragment,
+    O: QueryFragment + ValidOrderingForDistinct<D>,
     L: QueryFragment,
+    D: QueryFragment,
+    Lk: QueryFragment + ValidLockingClause<S, D>,
 {
     type SqlType = types::Array<ST>;
 
     fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
         out.push_sql("SELECT ");
+        try!(self.distinct.to_sql(out));
         try!(self.select.to_sql(out));
         out.push_sql(" FROM ");
         try!(self.from.from_clause(out));
         try!(self.where_clause.to_sql(out));
         try!(self.order.to_sql(out));
-        self.limit.to_sql(out)
+        try!(self.limit.to_sql(out));
+        self.lock.to_sql(out)
     }
 }
 
-impl<ST, S, F, W, O, L, QS> SelectableExpression<QS> for SelectStatement<ST, S, F, W, O, L> where
-    SelectStatement<ST, S, F, W, O, L>: Expression,
+impl<ST, S, F, W, O, L, D, Lk, QS> SelectableExpression<QS> for SelectStatement<ST, S, F, W, O, L, D, Lk> where
+    SelectStatement<ST, S, F, W, O, L, D, Lk>: Expression,
 {
 }
 
-impl<ST, S, F, W, O, L> NonAggregate for SelectStatement<ST, S, F, W, O, L> where
-    SelectStatement<ST, S, F, W, O, L>: Expression,
+impl<ST, S, F, W, O, L, D, Lk> NonAggregate for SelectStatement<ST, S, F, W, O, L, D, Lk> where
+    SelectStatement<ST, S, F, W, O, L, D, Lk>: Expression,
 {
 }
@@ -0,0 +1,215 @@
+use expression::{AsExpression, Expression};
+use expression::ordering::Desc;
+use query_dsl::{LimitDsl, OrderDsl};
+use query_source::Column;
+use std::marker::PhantomData;
+use super::{Query, QueryFragment, QueryBuilder, BuildQueryResult};
+use super::limit_clause::{NoLimitClause, LimitClause};
+use super::order_clause::NoOrderClause;
+use super::select_statement::SelectStatement;
+use types;
+
+/// Two queries with the same SQL type, combined with `UNION`, `UNION ALL`,
+/// `INTERSECT` or `EXCEPT`. Constructed with the methods on `CombineDsl`.
+#[derive(Debug, Clone, Copy)]
+pub struct CompoundSelect<Op, Lhs, Rhs, Order = NoOrderClause, Limit = NoLimitClause> {
+    operator: Op,
+    lhs: Lhs,
+    rhs: Rhs,
+    order: Order,
+    limit: Limit,
+}
+
+impl<Op, Lhs, Rhs> CompoundSelect<Op, Lhs, Rhs> {
+    pub fn new(operator: Op, lhs: Lhs, rhs: Rhs) -> Self {
+        CompoundSelect {
+            operator: operator,
+            lhs: lhs,
+            rhs: rhs,
+            order: NoOrderClause,
+            limit: NoLimitClause,
+        }
+    }
+}
+
+impl<Op, Lhs, Rhs, O, L> QueryFragment for CompoundSelect<Op, Lhs, Rhs, O, L> where
+    Op: QueryFragment,
+    Lhs: Query,
+    Rhs: Query<SqlType=Lhs::SqlType>,
+    O: QueryFragment,
+    L: QueryFragment,
+{
+    fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        out.push_sql("(");
+        try!(self.lhs.to_sql(out));
+        out.push_sql(")");
+        try!(self.operator.to_sql(out));
+        out.push_sql("(");
+        try!(self.rhs.to_sql(out));
+        out.push_sql(")");
+        try!(self.order.to_sql(out));
+        self.limit.to_sql(out)
+    }
+}
+
+impl<Op, Lhs, Rhs, O, L> Query for CompoundSelect<Op, Lhs, Rhs, O, L> where
+    Lhs: Query,
+    CompoundSelect<Op, Lhs, Rhs, O, L>: QueryFragment,
+{
+    type SqlType = Lhs::SqlType;
+}
+
+impl<Op, Lhs, Rhs, O, L, Expr, Index> OrderDsl<Expr, Index> for CompoundSelect<Op, Lhs, Rhs, O, L> where
+    Lhs: QuerySelection,
+    Expr: Expression + CompoundOrder<Lhs::Selection, Index>,
+    CompoundSelect<Op, Lhs, Rhs, CompoundOrderClause<Expr, Index>, L>: Query,
+{
+    type Output = CompoundSelect<Op, Lhs, Rhs, CompoundOrderClause<Expr, Index>, L>;
+
+    fn order(self, expr: Expr) -> Self::Output {
+        CompoundSelect {
+            operator: self.operator,
+            lhs: self.lhs,
+            rhs: self.rhs,
+            order: CompoundOrderClause(expr, PhantomData),
+            limit: self.limit,
+        }
+    }
+}
+
+type Limit = <i64 as AsExpression<types::BigInt>>::Expression;
+
+impl<Op, Lhs, Rhs, O, L> LimitDsl for CompoundSelect<Op, Lhs, Rhs, O, L> where
+    CompoundSelect<Op, Lhs, Rhs, O, LimitClause<Limit>>: Query,
+{
+    type Output = CompoundSelect<Op, Lhs, Rhs, O, LimitClause<Limit>>;
+
+    fn limit(self, limit: i64) -> Self::Output {
+        CompoundSelect {
+            operator: self.operator,
+            lhs: self.lhs,
+            rhs: self.rhs,
+            order: self.order,
+            limit: LimitClause(AsExpression::<types::BigInt>::as_expression(limit)),
+        }
+    }
+}
+
+macro_rules! set_operator {
+    ($name:ident, $sql:expr) => {
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+
+        impl QueryFragment for $name {
+            fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+                out.push_sql($sql);
+                Ok(())
+            }
+        }
+    }
+}
+
+set_operator!(Union, " UNION ");
+set_operator!(UnionAll, " UNION ALL ");
+set_operator!(Intersect, " INTERSECT ");
+set_operator!(Except, " EXCEPT ");
+
+/// Queries which select a known set of columns, which the `ORDER BY` clause
+/// of a compound select can refer to.
+pub trait QuerySelection {
+    type Selection;
+}
+
+impl<ST, S, F, W, O, L, D, Lk> QuerySelection for SelectStatement<ST, S, F, W, O, L, D, Lk> {
+    type Selection = S;
+}
+
+impl<Op, Lhs, Rhs, O, L> QuerySelection for CompoundSelect<Op, Lhs, Rhs, O, L> where
+    Lhs: QuerySelection,
+{
+    type Selection = Lhs::Selection;
+}
+
+/// Implemented for columns which are part of `Selection`. `Position` is the
+/// index of the column within a tuple, encoded as the length of an array, so
+/// that each column of a tuple has its own impl.
+pub trait SelectedColumn<Selection, Position> {
+}
+
+impl<C: Column> SelectedColumn<C, ()> for C {}
+
+/// The position of a column within the selection, counting from 1. `()` is
+/// the only column of a selection which isn't a tuple.
+pub trait SelectionPosition {
+    fn position() -> usize;
+}
+
+impl SelectionPosition for () {
+    fn position() -> usize {
+        1
+    }
+}
+
+macro_rules! selection_positions {
+    ($($idx:tt)+) => {
+        $(impl SelectionPosition for [(); $idx] {
+            fn position() -> usize {
+                $idx + 1
+            }
+        })+
+    }
+}
+
+selection_positions!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15);
+
+/// PG only allows the `ORDER BY` clause of a compound select to reference its
+/// output columns, so it can only be ordered by columns selected by the first
+/// query, or a tuple of them. Any of them may be descending.
+pub trait CompoundOrder<Selection, Index>: CompoundOrderFragment<Index> {
+}
+
+impl<C, S, I> CompoundOrder<S, I> for C where
+    C: Column + SelectedColumn<S, I>,
+    I: SelectionPosition,
+{
+}
+
+impl<C, S, I> CompoundOrder<S, I> for Desc<C> where
+    C: Column + SelectedColumn<S, I>,
+    I: SelectionPosition,
+{
+}
+
+/// Columns are rendered by their position in the selection, as the `ORDER BY`
+/// clause of a compound select refers to its output columns, and two selected
+/// columns from different tables may have the same name.
+pub trait CompoundOrderFragment<Index> {
+    fn to_compound_order_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult;
+}
+
+impl<C: Column, I: SelectionPosition> CompoundOrderFragment<I> for C {
+    fn to_compound_order_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        out.push_sql(&I::position().to_string());
+        Ok(())
+    }
+}
+
+impl<C: Column, I: SelectionPosition> CompoundOrderFragment<I> for Desc<C> {
+    fn to_compound_order_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        out.push_sql(&I::position().to_string());
+        out.push_sql(" DESC");
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CompoundOrderClause<Expr, Index>(Expr, PhantomData<Index>);
+
+impl<Expr, Index> QueryFragment for CompoundOrderClause<Expr, Index> where
+    Expr: CompoundOrderFragment<Index>,
+{
+    fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        out.push_sql(" ORDER BY ");
+        self.0.to_compound_order_sql(out)
+    }
+}
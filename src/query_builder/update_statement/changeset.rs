@@ -10,9 +10,44 @@ pub trait AsChangeset {
 pub trait Changeset {
     type Target: QuerySource;
 
+    /// Whether this changeset has no assignments to make, e.g. when every
+    /// field of a `changeset!` declared with `#[skip_none]` was `None`.
+    fn is_noop(&self) -> bool {
+        false
+    }
+
     fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult;
 }
 
+/// A changeset whose values can all be selected from `QS`. Updates check their
+/// changeset against the update target, so values can refer to the tables in
+/// its `FROM` clause.
+pub trait SelectableChangeset<QS>: Changeset {
+}
+
+impl<T: Changeset> Changeset for Option<T> {
+    type Target = T::Target;
+
+    fn is_noop(&self) -> bool {
+        match *self {
+            Some(ref changeset) => changeset.is_noop(),
+            None => true,
+        }
+    }
+
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        match *self {
+            Some(ref changeset) => changeset.to_sql(out),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T, QS> SelectableChangeset<QS> for Option<T> where
+    T: SelectableChangeset<QS>,
+{
+}
+
 impl<T> AsChangeset for T where
     T: Changeset,
 {
@@ -1,12 +1,12 @@
 pub mod changeset;
 pub mod target;
 
-pub use self::changeset::{Changeset, AsChangeset};
+pub use self::changeset::{Changeset, AsChangeset, SelectableChangeset};
 pub use self::target::UpdateTarget;
 
 use expression::Expression;
 use query_builder::{Query, AsQuery, QueryFragment, QueryBuilder, BuildQueryResult};
-use query_source::Table;
+use query_source::{QuerySource, Table};
 
 pub fn update<T: UpdateTarget>(source: T) -> IncompleteUpdateStatement<T> {
     IncompleteUpdateStatement(source)
@@ -33,14 +33,25 @@ pub struct UpdateStatement<T, U> {
 
 impl<T, U> QueryFragment for UpdateStatement<T, U> where
     T: UpdateTarget,
-    U: changeset::Changeset<Target=T::Table>,
+    U: changeset::Changeset<Target=T::Table> + changeset::SelectableChangeset<T>,
 {
     fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        if self.values.is_noop() {
+            return Err("There are no changes to save. This update has no assignments".into());
+        }
         out.push_sql("UPDATE ");
-        try!(self.target.from_clause(out));
+        try!(self.target.table().from_clause(out));
         out.push_sql(" SET ");
         try!(self.values.to_sql(out));
-        self.target.where_clause(out)
+        if self.target.has_other_tables() {
+            out.push_sql(" FROM ");
+            try!(self.target.other_tables(out));
+        }
+        if self.target.has_predicates() {
+            out.push_sql(" WHERE ");
+            try!(self.target.predicates(out));
+        }
+        Ok(())
     }
 }
 
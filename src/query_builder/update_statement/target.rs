@@ -1,9 +1,30 @@
 use query_builder::{QueryBuilder, BuildQueryResult};
 use query_source::{QuerySource, Table};
 
+/// The rows affected by an update or delete. Either a table, a filtered table,
+/// or an inner join whose left side is one of those. Joined tables are rendered
+/// with `UPDATE ... FROM` or `DELETE ... USING`, and their join conditions are
+/// added to the where clause.
 pub trait UpdateTarget: QuerySource {
     type Table: Table;
 
-    fn where_clause<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult;
     fn table(&self) -> &Self::Table;
+
+    fn has_other_tables(&self) -> bool {
+        false
+    }
+
+    /// Renders the tables other than `Self::Table`, separated by commas.
+    fn other_tables<T: QueryBuilder>(&self, _out: &mut T) -> BuildQueryResult {
+        Ok(())
+    }
+
+    fn has_predicates(&self) -> bool {
+        false
+    }
+
+    /// Renders the conditions rows must match, separated by `AND`.
+    fn predicates<T: QueryBuilder>(&self, _out: &mut T) -> BuildQueryResult {
+        Ok(())
+    }
 }
@@ -1,6 +1,6 @@
 use expression::*;
 use expression::predicates::And;
-use super::{QueryFragment, QueryBuilder, BuildQueryResult};
+use super::{QueryFragment, BoxableQueryFragment, QueryBuilder, BuildQueryResult};
 use types::Bool;
 
 pub trait WhereAnd<Predicate: Expression<SqlType=Bool>> {
@@ -46,3 +46,46 @@ impl<Expr, Predicate> WhereAnd<Predicate> for WhereClause<Expr> where
         WhereClause(self.0.and(predicate))
     }
 }
+
+/// The where clause of a boxed query. Each predicate is stored separately and
+/// the predicates are joined with `AND`.
+pub struct BoxedWhereClause<'a>(Vec<Box<BoxableQueryFragment + 'a>>);
+
+impl<'a> QueryFragment for BoxedWhereClause<'a> {
+    fn to_sql<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        for (i, predicate) in self.0.iter().enumerate() {
+            out.push_sql(if i == 0 { " WHERE " } else { " AND " });
+            try!(predicate.to_boxed_sql(out));
+        }
+        Ok(())
+    }
+}
+
+impl<'a, Predicate> WhereAnd<Predicate> for BoxedWhereClause<'a> where
+    Predicate: Expression<SqlType=Bool> + 'a,
+{
+    type Output = Self;
+
+    fn and(mut self, predicate: Predicate) -> Self::Output {
+        self.0.push(Box::new(predicate));
+        self
+    }
+}
+
+pub trait IntoBoxedWhereClause<'a> {
+    fn into_boxed_where_clause(self) -> BoxedWhereClause<'a>;
+}
+
+impl<'a> IntoBoxedWhereClause<'a> for NoWhereClause {
+    fn into_boxed_where_clause(self) -> BoxedWhereClause<'a> {
+        BoxedWhereClause(Vec::new())
+    }
+}
+
+impl<'a, Expr> IntoBoxedWhereClause<'a> for WhereClause<Expr> where
+    Expr: Expression<SqlType=Bool> + 'a,
+{
+    fn into_boxed_where_clause(self) -> BoxedWhereClause<'a> {
+        BoxedWhereClause(vec![Box::new(self.0)])
+    }
+}
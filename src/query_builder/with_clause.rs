@@ -0,0 +1,181 @@
+use expression::Expression;
+use persistable::InsertableColumns;
+use query_dsl::{LimitDsl, OrderDsl};
+use query_source::Table;
+use std::marker::PhantomData;
+use super::{Query, AsQuery, QueryFragment, QueryBuilder, BuildQueryResult};
+
+/// Defines a common table expression. The shape of the CTE is declared with
+/// `table!`, which gives it typed columns that can be selected, filtered, and
+/// joined like any other table. The SQL type of the query must match the
+/// columns of the table.
+pub fn with<T, Q>(table: T, query: Q) -> WithClause<Cte<T, Q::Query>> where
+    T: Table,
+    Q: AsQuery<SqlType=<T::AllColumns as Expression>::SqlType>,
+{
+    WithClause {
+        ctes: Cte::new(table, query.as_query()),
+        recursive: false,
+    }
+}
+
+/// Defines a common table expression which can refer to itself. The query will
+/// usually be the `union_all` of a base case and a recursive case which joins
+/// against the table.
+pub fn with_recursive<T, Q>(table: T, query: Q) -> WithClause<Cte<T, Q::Query>> where
+    T: Table,
+    Q: AsQuery<SqlType=<T::AllColumns as Expression>::SqlType>,
+{
+    WithClause {
+        ctes: Cte::new(table, query.as_query()),
+        recursive: true,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WithClause<Ctes> {
+    ctes: Ctes,
+    recursive: bool,
+}
+
+impl<Ctes> WithClause<Ctes> {
+    pub fn with<T, Q>(self, table: T, query: Q) -> WithClause<ChainedCte<Ctes, Cte<T, Q::Query>>> where
+        T: Table,
+        Q: AsQuery<SqlType=<T::AllColumns as Expression>::SqlType>,
+    {
+        let cte = Cte::new(table, query.as_query());
+        WithClause {
+            ctes: ChainedCte(self.ctes, cte),
+            recursive: self.recursive,
+        }
+    }
+
+    pub fn with_recursive<T, Q>(self, table: T, query: Q)
+        -> WithClause<ChainedCte<Ctes, Cte<T, Q::Query>>> where
+        T: Table,
+        Q: AsQuery<SqlType=<T::AllColumns as Expression>::SqlType>,
+    {
+        let cte = Cte::new(table, query.as_query());
+        WithClause {
+            ctes: ChainedCte(self.ctes, cte),
+            recursive: true,
+        }
+    }
+
+    pub fn query<Q: AsQuery>(self, query: Q) -> WithQuery<Ctes, Q::Query> {
+        WithQuery {
+            with_clause: self,
+            query: query.as_query(),
+        }
+    }
+}
+
+impl<Ctes: QueryFragment> QueryFragment for WithClause<Ctes> {
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        if self.recursive {
+            out.push_sql("WITH RECURSIVE ");
+        } else {
+            out.push_sql("WITH ");
+        }
+        try!(self.ctes.to_sql(out));
+        out.push_sql(" ");
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Cte<T, Q> {
+    table: PhantomData<T>,
+    query: Q,
+}
+
+impl<T, Q> Cte<T, Q> {
+    fn new(_table: T, query: Q) -> Self {
+        Cte {
+            table: PhantomData,
+            query: query,
+        }
+    }
+}
+
+impl<T, Q> QueryFragment for Cte<T, Q> where
+    T: Table,
+    T::AllColumns: InsertableColumns<T>,
+    Q: Query<SqlType=<T::AllColumns as Expression>::SqlType>,
+{
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        try!(out.push_identifier(T::name()));
+        out.push_sql(" (");
+        try!(T::all_columns().names_to_sql(out));
+        out.push_sql(") AS (");
+        try!(self.query.to_sql(out));
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ChainedCte<Head, Tail>(Head, Tail);
+
+impl<Head, Tail> QueryFragment for ChainedCte<Head, Tail> where
+    Head: QueryFragment,
+    Tail: QueryFragment,
+{
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        try!(self.0.to_sql(out));
+        out.push_sql(", ");
+        self.1.to_sql(out)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct WithQuery<Ctes, Q> {
+    with_clause: WithClause<Ctes>,
+    query: Q,
+}
+
+impl<Ctes, Q> QueryFragment for WithQuery<Ctes, Q> where
+    Ctes: QueryFragment,
+    Q: Query,
+{
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        try!(self.with_clause.to_sql(out));
+        self.query.to_sql(out)
+    }
+}
+
+impl<Ctes, Q> Query for WithQuery<Ctes, Q> where
+    Ctes: QueryFragment,
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+impl<Ctes, Q, Expr, Index> OrderDsl<Expr, Index> for WithQuery<Ctes, Q> where
+    Expr: Expression,
+    Q: OrderDsl<Expr, Index>,
+    WithQuery<Ctes, Q::Output>: Query,
+{
+    type Output = WithQuery<Ctes, Q::Output>;
+
+    fn order(self, expr: Expr) -> Self::Output {
+        WithQuery {
+            with_clause: self.with_clause,
+            query: self.query.order(expr),
+        }
+    }
+}
+
+impl<Ctes, Q> LimitDsl for WithQuery<Ctes, Q> where
+    Q: LimitDsl,
+    WithQuery<Ctes, Q::Output>: Query,
+{
+    type Output = WithQuery<Ctes, Q::Output>;
+
+    fn limit(self, limit: i64) -> Self::Output {
+        WithQuery {
+            with_clause: self.with_clause,
+            query: self.query.limit(limit),
+        }
+    }
+}
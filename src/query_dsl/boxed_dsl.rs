@@ -0,0 +1,24 @@
+use query_builder::{Query, AsQuery};
+use query_source::QuerySource;
+
+pub type BoxedOutput<'a, T> = <T as BoxedDsl<'a>>::Output;
+
+/// Erases the types of a query's clauses, so that it keeps the same type when
+/// it is filtered, ordered or limited. Queries using `distinct` or locking
+/// clauses cannot be boxed.
+pub trait BoxedDsl<'a> {
+    type Output: Query;
+
+    fn into_boxed(self) -> Self::Output;
+}
+
+impl<'a, T> BoxedDsl<'a> for T where
+    T: QuerySource + AsQuery,
+    T::Query: BoxedDsl<'a>,
+{
+    type Output = BoxedOutput<'a, T::Query>;
+
+    fn into_boxed(self) -> Self::Output {
+        self.as_query().into_boxed()
+    }
+}
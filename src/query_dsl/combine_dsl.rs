@@ -0,0 +1,38 @@
+use query_builder::AsQuery;
+use query_builder::set_operation::*;
+
+pub type CombineOutput<Op, Lhs, Rhs> = CompoundSelect<
+    Op,
+    <Lhs as AsQuery>::Query,
+    <Rhs as AsQuery>::Query,
+>;
+
+/// Combines two queries using set operations. Both queries must have the same
+/// SQL type.
+pub trait CombineDsl: AsQuery + Sized {
+    fn union<Rhs>(self, rhs: Rhs) -> CombineOutput<Union, Self, Rhs> where
+        Rhs: AsQuery<SqlType=Self::SqlType>,
+    {
+        CompoundSelect::new(Union, self.as_query(), rhs.as_query())
+    }
+
+    fn union_all<Rhs>(self, rhs: Rhs) -> CombineOutput<UnionAll, Self, Rhs> where
+        Rhs: AsQuery<SqlType=Self::SqlType>,
+    {
+        CompoundSelect::new(UnionAll, self.as_query(), rhs.as_query())
+    }
+
+    fn intersect<Rhs>(self, rhs: Rhs) -> CombineOutput<Intersect, Self, Rhs> where
+        Rhs: AsQuery<SqlType=Self::SqlType>,
+    {
+        CompoundSelect::new(Intersect, self.as_query(), rhs.as_query())
+    }
+
+    fn except<Rhs>(self, rhs: Rhs) -> CombineOutput<Except, Self, Rhs> where
+        Rhs: AsQuery<SqlType=Self::SqlType>,
+    {
+        CompoundSelect::new(Except, self.as_query(), rhs.as_query())
+    }
+}
+
+impl<T: AsQuery> CombineDsl for T {}
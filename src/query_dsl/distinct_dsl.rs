@@ -0,0 +1,44 @@
+use expression::Expression;
+use query_builder::{Query, AsQuery};
+use query_source::QuerySource;
+
+pub type DistinctOutput<T> = <T as DistinctDsl>::Output;
+pub type DistinctOnOutput<T, Expr> = <T as DistinctOnDsl<Expr>>::Output;
+
+pub trait DistinctDsl {
+    type Output: Query;
+
+    fn distinct(self) -> Self::Output;
+}
+
+impl<T> DistinctDsl for T where
+    T: QuerySource + AsQuery,
+    T::Query: DistinctDsl,
+{
+    type Output = <T::Query as DistinctDsl>::Output;
+
+    fn distinct(self) -> Self::Output {
+        self.as_query().distinct()
+    }
+}
+
+/// Adds a `DISTINCT ON` clause to the query. If the query is ordered, PG
+/// requires the leftmost `ORDER BY` expression to match the given expression,
+/// which is checked at compile time.
+pub trait DistinctOnDsl<Expr: Expression> {
+    type Output: Query;
+
+    fn distinct_on(self, expr: Expr) -> Self::Output;
+}
+
+impl<T, Expr> DistinctOnDsl<Expr> for T where
+    Expr: Expression,
+    T: QuerySource + AsQuery,
+    T::Query: DistinctOnDsl<Expr>,
+{
+    type Output = DistinctOnOutput<T::Query, Expr>;
+
+    fn distinct_on(self, expr: Expr) -> Self::Output {
+        self.as_query().distinct_on(expr)
+    }
+}
@@ -3,7 +3,8 @@ use expression::{Expression, NonAggregate};
 use expression::helper_types::AsExpr;
 use query_builder::AsQuery;
 use query_source::filter::FilteredQuerySource;
-use query_source::{Table, InnerJoinSource, LeftOuterJoinSource};
+use query_source::{Table, InnerJoinSource, LeftOuterJoinSource, RightOuterJoinSource};
+use query_source::{FullOuterJoinSource, CrossJoinSource};
 use types::Bool;
 
 pub type FilterOutput<T, P> = <T as FilterDsl<P>>::Output;
@@ -31,5 +32,8 @@ impl<T, Predicate> FilterDsl<Predicate> for T where
 }
 
 impl<T: Table> NotFiltered for T {}
-impl<Left, Right> NotFiltered for InnerJoinSource<Left, Right> {}
-impl<Left, Right> NotFiltered for LeftOuterJoinSource<Left, Right> {}
+impl<Left, Right, On> NotFiltered for InnerJoinSource<Left, Right, On> {}
+impl<Left, Right, On> NotFiltered for LeftOuterJoinSource<Left, Right, On> {}
+impl<Left, Right, On> NotFiltered for RightOuterJoinSource<Left, Right, On> {}
+impl<Left, Right, On> NotFiltered for FullOuterJoinSource<Left, Right, On> {}
+impl<Left, Right> NotFiltered for CrossJoinSource<Left, Right> {}
@@ -0,0 +1,76 @@
+use expression::Expression;
+use query_source::*;
+use types::Bool;
+
+pub type InnerJoinOutput<Source, Rhs, Via> = InnerJoinSource<
+    Source,
+    <Rhs as JoinTarget<Source, Via>>::Table,
+    <Rhs as JoinTarget<Source, Via>>::Predicate,
+>;
+pub type LeftOuterJoinOutput<Source, Rhs, Via> = LeftOuterJoinSource<
+    Source,
+    <Rhs as JoinTarget<Source, Via>>::Table,
+    <Rhs as JoinTarget<Source, Via>>::Predicate,
+>;
+pub type RightOuterJoinOutput<Source, Rhs, Via> = RightOuterJoinSource<
+    Source,
+    <Rhs as JoinTarget<Source, Via>>::Table,
+    <Rhs as JoinTarget<Source, Via>>::Predicate,
+>;
+pub type FullOuterJoinOutput<Source, Rhs, Via> = FullOuterJoinSource<
+    Source,
+    <Rhs as JoinTarget<Source, Via>>::Table,
+    <Rhs as JoinTarget<Source, Via>>::Predicate,
+>;
+
+/// Joins a table onto a table or an existing join. When given a table, the join
+/// condition comes from the `joinable!` relationship between the new table and
+/// any table already in the query source. A table wrapped with `.on` is joined
+/// using the given condition instead.
+pub trait JoinDsl: Sized {
+    fn inner_join<Rhs, Via>(self, rhs: Rhs) -> InnerJoinOutput<Self, Rhs, Via> where
+        Rhs: JoinTarget<Self, Via>,
+    {
+        let (table, predicate) = rhs.into_table_and_predicate(&self);
+        InnerJoinSource::new(self, table, predicate)
+    }
+
+    fn left_outer_join<Rhs, Via>(self, rhs: Rhs) -> LeftOuterJoinOutput<Self, Rhs, Via> where
+        Rhs: JoinTarget<Self, Via>,
+    {
+        let (table, predicate) = rhs.into_table_and_predicate(&self);
+        LeftOuterJoinSource::new(self, table, predicate)
+    }
+
+    fn right_outer_join<Rhs, Via>(self, rhs: Rhs) -> RightOuterJoinOutput<Self, Rhs, Via> where
+        Rhs: JoinTarget<Self, Via>,
+    {
+        let (table, predicate) = rhs.into_table_and_predicate(&self);
+        RightOuterJoinSource::new(self, table, predicate)
+    }
+
+    fn full_outer_join<Rhs, Via>(self, rhs: Rhs) -> FullOuterJoinOutput<Self, Rhs, Via> where
+        Rhs: JoinTarget<Self, Via>,
+    {
+        let (table, predicate) = rhs.into_table_and_predicate(&self);
+        FullOuterJoinSource::new(self, table, predicate)
+    }
+
+    fn cross_join<T: Table>(self, other: T) -> CrossJoinSource<Self, T> {
+        CrossJoinSource::new(self, other)
+    }
+}
+
+impl<T> JoinDsl for T {}
+
+pub trait JoinOnDsl: QuerySource {
+    /// Provides the condition used to join this table, in place of the one
+    /// declared with `joinable!`.
+    fn on<On>(self, on: On) -> OnClauseWrapper<Self, On> where
+        On: Expression<SqlType=Bool>,
+    {
+        OnClauseWrapper::new(self, on)
+    }
+}
+
+impl<T: Table> JoinOnDsl for T {}
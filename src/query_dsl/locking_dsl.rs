@@ -0,0 +1,75 @@
+use query_builder::{Query, AsQuery};
+use query_builder::locking_clause::*;
+use query_source::QuerySource;
+
+pub type LockingOutput<T, Mode> = <T as LockingDsl<Mode>>::Output;
+pub type ModifyLockOutput<T, Modifier> = <T as ModifyLockDsl<Modifier>>::Output;
+
+/// Adds a row level locking clause (e.g. `FOR UPDATE`) to the query. You will
+/// generally want to use the methods on `RowLockingDsl` rather than calling
+/// this directly.
+pub trait LockingDsl<Mode> {
+    type Output: Query;
+
+    fn with_lock(self, mode: Mode) -> Self::Output;
+}
+
+impl<T, Mode> LockingDsl<Mode> for T where
+    T: QuerySource + AsQuery,
+    T::Query: LockingDsl<Mode>,
+{
+    type Output = LockingOutput<T::Query, Mode>;
+
+    fn with_lock(self, mode: Mode) -> Self::Output {
+        self.as_query().with_lock(mode)
+    }
+}
+
+/// Changes how a locking clause behaves when rows are already locked (e.g.
+/// `SKIP LOCKED`). Only implemented for queries which already have a locking
+/// clause.
+pub trait ModifyLockDsl<Modifier> {
+    type Output: Query;
+
+    fn modify_lock(self, modifier: Modifier) -> Self::Output;
+}
+
+pub trait RowLockingDsl: Sized {
+    fn for_update(self) -> LockingOutput<Self, ForUpdate> where
+        Self: LockingDsl<ForUpdate>,
+    {
+        self.with_lock(ForUpdate)
+    }
+
+    fn for_no_key_update(self) -> LockingOutput<Self, ForNoKeyUpdate> where
+        Self: LockingDsl<ForNoKeyUpdate>,
+    {
+        self.with_lock(ForNoKeyUpdate)
+    }
+
+    fn for_share(self) -> LockingOutput<Self, ForShare> where
+        Self: LockingDsl<ForShare>,
+    {
+        self.with_lock(ForShare)
+    }
+
+    fn for_key_share(self) -> LockingOutput<Self, ForKeyShare> where
+        Self: LockingDsl<ForKeyShare>,
+    {
+        self.with_lock(ForKeyShare)
+    }
+
+    fn skip_locked(self) -> ModifyLockOutput<Self, SkipLocked> where
+        Self: ModifyLockDsl<SkipLocked>,
+    {
+        self.modify_lock(SkipLocked)
+    }
+
+    fn no_wait(self) -> ModifyLockOutput<Self, NoWait> where
+        Self: ModifyLockDsl<NoWait>,
+    {
+        self.modify_lock(NoWait)
+    }
+}
+
+impl<T> RowLockingDsl for T {}
@@ -1,13 +1,24 @@
+mod boxed_dsl;
+mod combine_dsl;
 mod count_dsl;
+mod distinct_dsl;
+mod join_dsl;
 pub mod limit_dsl;
 mod load_dsl;
+mod locking_dsl;
 mod select_dsl;
 pub mod filter_dsl;
 mod order_dsl;
 
+pub use self::boxed_dsl::{BoxedDsl, BoxedOutput};
+pub use self::combine_dsl::{CombineDsl, CombineOutput};
 pub use self::count_dsl::CountDsl;
+pub use self::distinct_dsl::{DistinctDsl, DistinctOnDsl, DistinctOutput, DistinctOnOutput};
+pub use self::join_dsl::{JoinDsl, JoinOnDsl, InnerJoinOutput, LeftOuterJoinOutput};
+pub use self::join_dsl::{RightOuterJoinOutput, FullOuterJoinOutput};
 pub use self::limit_dsl::{LimitDsl, LimitOutput};
 pub use self::load_dsl::LoadDsl;
+pub use self::locking_dsl::{LockingDsl, ModifyLockDsl, RowLockingDsl, LockingOutput, ModifyLockOutput};
 pub use self::select_dsl::{SelectDsl, SelectSqlDsl, SelectOutput};
 pub use self::filter_dsl::{FilterDsl, FilterOutput, FindByOutput};
 pub use self::order_dsl::{OrderDsl, OrderOutput};
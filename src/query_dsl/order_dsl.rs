@@ -2,9 +2,11 @@ use expression::Expression;
 use query_builder::{Query, AsQuery};
 use query_source::QuerySource;
 
-pub type OrderOutput<Target, Expr> = <Target as OrderDsl<Expr>>::Output;
+pub type OrderOutput<Target, Expr, Index = ()> = <Target as OrderDsl<Expr, Index>>::Output;
 
-pub trait OrderDsl<Expr: Expression> {
+/// `Index` is only used by compound selects, where it is the position of each
+/// ordering column within the selection, and is inferred.
+pub trait OrderDsl<Expr: Expression, Index = ()> {
     type Output: Query;
 
     fn order(self, expr: Expr) -> Self::Output;
@@ -0,0 +1,74 @@
+use expression::{Expression, SelectableExpression, NonAggregate};
+use query_builder::{QueryBuilder, BuildQueryResult};
+use std::marker::PhantomData;
+use super::{Table, Column, AppearsInFromClause, SelectableColumnType, ColumnTypeIn};
+
+/// A table given a different name in the from clause, declared with `alias!`.
+/// The alias is a separate query source from its table, so the table can be
+/// joined to itself.
+pub trait AliasSource: Copy {
+    type Table: Table;
+
+    fn name() -> &'static str;
+}
+
+/// One or more columns of a table, which can be rendered against an alias of
+/// that table rather than the table itself.
+pub trait AliasableColumns: Expression {
+    type Table: Table;
+
+    fn to_sql_with_alias<B: QueryBuilder>(alias: &str, out: &mut B) -> BuildQueryResult;
+}
+
+impl<C: Column<Table=T>, T: Table> AliasableColumns for C {
+    type Table = T;
+
+    fn to_sql_with_alias<B: QueryBuilder>(alias: &str, out: &mut B) -> BuildQueryResult {
+        try!(out.push_identifier(alias));
+        out.push_sql(".");
+        out.push_identifier(C::name())
+    }
+}
+
+/// A column (or tuple of columns) of the table `A` aliases, referenced through
+/// the alias. Created with `alias.field(column)`.
+#[derive(Debug, Clone, Copy)]
+pub struct AliasedField<A, C> {
+    alias: PhantomData<A>,
+    columns: PhantomData<C>,
+}
+
+impl<A, C> AliasedField<A, C> {
+    pub fn new(_alias: A, _columns: C) -> Self {
+        AliasedField {
+            alias: PhantomData,
+            columns: PhantomData,
+        }
+    }
+}
+
+impl<A, C> Expression for AliasedField<A, C> where
+    A: AliasSource,
+    C: AliasableColumns<Table=A::Table>,
+{
+    type SqlType = C::SqlType;
+
+    fn to_sql<B: QueryBuilder>(&self, out: &mut B) -> BuildQueryResult {
+        C::to_sql_with_alias(A::name(), out)
+    }
+}
+
+impl<A, C, QS> SelectableExpression<QS, ColumnTypeIn<QS, A, C::SqlType>>
+    for AliasedField<A, C> where
+    A: AliasSource,
+    C: AliasableColumns<Table=A::Table>,
+    QS: AppearsInFromClause<A>,
+    QS::Presence: SelectableColumnType<C::SqlType>,
+{
+}
+
+impl<A, C> NonAggregate for AliasedField<A, C> where
+    AliasedField<A, C>: Expression,
+    C: NonAggregate,
+{
+}
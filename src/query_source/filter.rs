@@ -2,7 +2,7 @@ use expression::{Expression, SelectableExpression, NonAggregate};
 use expression::predicates::And;
 use query_builder::*;
 use query_dsl::{FilterDsl, FilterOutput};
-use query_source::QuerySource;
+use query_source::{QuerySource, AppearsInFromClause};
 use types::Bool;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -55,18 +55,39 @@ impl<Source, Predicate> QuerySource for FilteredQuerySource<Source, Predicate> w
     }
 }
 
+impl<Source, Predicate, T> AppearsInFromClause<T> for FilteredQuerySource<Source, Predicate> where
+    Source: AppearsInFromClause<T>,
+{
+    type Presence = Source::Presence;
+}
+
 impl<Source, Predicate> UpdateTarget for FilteredQuerySource<Source, Predicate> where
     Source: UpdateTarget,
     Predicate: SelectableExpression<Source, SqlType=Bool>,
 {
     type Table = Source::Table;
 
-    fn where_clause<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
-        out.push_sql(" WHERE ");
-        self.predicate.to_sql(out)
-    }
-
     fn table(&self) -> &Self::Table {
         self.source.table()
     }
+
+    fn has_other_tables(&self) -> bool {
+        self.source.has_other_tables()
+    }
+
+    fn other_tables<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        self.source.other_tables(out)
+    }
+
+    fn has_predicates(&self) -> bool {
+        true
+    }
+
+    fn predicates<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        if self.source.has_predicates() {
+            try!(self.source.predicates(out));
+            out.push_sql(" AND ");
+        }
+        self.predicate.to_sql(out)
+    }
 }
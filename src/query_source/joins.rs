@@ -1,101 +1,280 @@
 use {QuerySource, Table};
 use query_builder::*;
-use expression::{Expression, SelectableExpression};
-use types::{Bool, Nullable};
+use expression::{Expression, SelectableExpression, NonAggregate};
+use types::{Bool, NativeSqlType, Nullable};
 
-#[derive(Clone, Copy)]
-pub struct InnerJoinSource<Left, Right> {
-    left: Left,
-    right: Right,
+macro_rules! find_join_predicate_through_join {
+    ($join:ident<$($param:ident),+>) => {
+        impl<$($param),+, T, Via> FindJoinPredicate<T, ViaLeft<Via>> for $join<$($param),+> where
+            Left: FindJoinPredicate<T, Via>,
+        {
+            type Predicate = Left::Predicate;
+
+            fn join_predicate(&self) -> Self::Predicate {
+                self.left.join_predicate()
+            }
+        }
+
+        impl<$($param),+, T, Via> FindJoinPredicate<T, ViaRight<Via>> for $join<$($param),+> where
+            Right: FindJoinPredicate<T, Via>,
+        {
+            type Predicate = Right::Predicate;
+
+            fn join_predicate(&self) -> Self::Predicate {
+                self.right.join_predicate()
+            }
+        }
+    }
 }
 
-impl<Left, Right> InnerJoinSource<Left, Right> {
-    pub fn new(left: Left, right: Right) -> Self {
-        InnerJoinSource {
-            left: left,
-            right: right,
+macro_rules! join_source {
+    ($name:ident, $sql:expr) => {
+        #[derive(Clone, Copy)]
+        pub struct $name<Left, Right, On> {
+            left: Left,
+            right: Right,
+            on: On,
+        }
+
+        impl<Left, Right, On> $name<Left, Right, On> {
+            pub fn new(left: Left, right: Right, on: On) -> Self {
+                $name {
+                    left: left,
+                    right: right,
+                    on: on,
+                }
+            }
+        }
+
+        impl<Left, Right, On> QuerySource for $name<Left, Right, On> where
+            Left: QuerySource,
+            Right: QuerySource,
+            On: Expression<SqlType=Bool>,
+        {
+            fn from_clause<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+                try!(self.left.from_clause(out));
+                out.push_sql($sql);
+                try!(self.right.from_clause(out));
+                out.push_sql(" ON ");
+                self.on.to_sql(out)
+            }
+        }
+
+        impl<Left, Right, On> AsQuery for $name<Left, Right, On> where
+            $name<Left, Right, On>: DefaultSelection,
+            SelectStatement<
+                <Self as DefaultSelection>::SqlType,
+                <Self as DefaultSelection>::Selection,
+                Self,
+            >: Query<SqlType=<Self as DefaultSelection>::SqlType>,
+        {
+            type SqlType = <Self as DefaultSelection>::SqlType;
+            type Query = SelectStatement<
+                <Self as DefaultSelection>::SqlType,
+                <Self as DefaultSelection>::Selection,
+                Self,
+            >;
+
+            fn as_query(self) -> Self::Query {
+                SelectStatement::simple(self.default_selection(), self)
+            }
         }
+
+        find_join_predicate_through_join!($name<Left, Right, On>);
     }
 }
 
-impl<Left, Right> QuerySource for InnerJoinSource<Left, Right> where
-    Left: Table + JoinTo<Right>,
-    Right: Table,
+join_source!(InnerJoinSource, " INNER JOIN ");
+join_source!(LeftOuterJoinSource, " LEFT OUTER JOIN ");
+join_source!(RightOuterJoinSource, " RIGHT OUTER JOIN ");
+join_source!(FullOuterJoinSource, " FULL OUTER JOIN ");
+
+impl<Left, Right, On> DefaultSelection for InnerJoinSource<Left, Right, On> where
+    Left: DefaultSelection,
+    Right: DefaultSelection,
+    InnerJoinSource<Left, Right, On>: QuerySource,
+    (Left::Selection, Right::Selection): SelectableExpression<
+        InnerJoinSource<Left, Right, On>,
+        (Left::SqlType, Right::SqlType),
+    >,
 {
-    fn from_clause<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
-        try!(self.left.from_clause(out));
-        out.push_sql(" INNER JOIN ");
-        try!(self.right.from_clause(out));
-        out.push_sql(" ON ");
-        Expression::to_sql(&self.left.join_expression(), out)
+    type SqlType = (Left::SqlType, Right::SqlType);
+    type Selection = (Left::Selection, Right::Selection);
+
+    fn default_selection(&self) -> Self::Selection {
+        (self.left.default_selection(), self.right.default_selection())
     }
 }
 
-impl<Left, Right> AsQuery for InnerJoinSource<Left, Right> where
-    Left: Table + JoinTo<Right>,
-    Right: Table,
-    (Left::AllColumns, Right::AllColumns): SelectableExpression<
-                                   InnerJoinSource<Left, Right>,
-                                   (Left::SqlType, Right::SqlType),
-                               >,
+impl<Left, Right, On> DefaultSelection for LeftOuterJoinSource<Left, Right, On> where
+    Left: DefaultSelection,
+    Right: DefaultSelection,
+    LeftOuterJoinSource<Left, Right, On>: QuerySource,
+    (Left::Selection, Right::Selection): SelectableExpression<
+        LeftOuterJoinSource<Left, Right, On>,
+        (Left::SqlType, Nullable<Right::SqlType>),
+    >,
 {
-    type SqlType = (Left::SqlType, Right::SqlType);
-    type Query = SelectStatement<
-        (Left::SqlType, Right::SqlType),
-        (Left::AllColumns, Right::AllColumns),
-        Self,
-    >;
+    type SqlType = (Left::SqlType, Nullable<Right::SqlType>);
+    type Selection = (Left::Selection, Right::Selection);
 
-    fn as_query(self) -> Self::Query {
-        SelectStatement::simple((Left::all_columns(), Right::all_columns()), self)
+    fn default_selection(&self) -> Self::Selection {
+        (self.left.default_selection(), self.right.default_selection())
+    }
+}
+
+impl<Left, Right, On> DefaultSelection for RightOuterJoinSource<Left, Right, On> where
+    Left: DefaultSelection,
+    Right: DefaultSelection,
+    RightOuterJoinSource<Left, Right, On>: QuerySource,
+    (Left::Selection, Right::Selection): SelectableExpression<
+        RightOuterJoinSource<Left, Right, On>,
+        (Nullable<Left::SqlType>, Right::SqlType),
+    >,
+{
+    type SqlType = (Nullable<Left::SqlType>, Right::SqlType);
+    type Selection = (Left::Selection, Right::Selection);
+
+    fn default_selection(&self) -> Self::Selection {
+        (self.left.default_selection(), self.right.default_selection())
+    }
+}
+
+impl<Left, Right, On> DefaultSelection for FullOuterJoinSource<Left, Right, On> where
+    Left: DefaultSelection,
+    Right: DefaultSelection,
+    FullOuterJoinSource<Left, Right, On>: QuerySource,
+    (Left::Selection, Right::Selection): SelectableExpression<
+        FullOuterJoinSource<Left, Right, On>,
+        (Nullable<Left::SqlType>, Nullable<Right::SqlType>),
+    >,
+{
+    type SqlType = (Nullable<Left::SqlType>, Nullable<Right::SqlType>);
+    type Selection = (Left::Selection, Right::Selection);
+
+    fn default_selection(&self) -> Self::Selection {
+        (self.left.default_selection(), self.right.default_selection())
+    }
+}
+
+impl<Left, Right, On> UpdateTarget for InnerJoinSource<Left, Right, On> where
+    Left: UpdateTarget,
+    Right: QuerySource,
+    On: Expression<SqlType=Bool>,
+{
+    type Table = Left::Table;
+
+    fn table(&self) -> &Self::Table {
+        self.left.table()
+    }
+
+    fn has_other_tables(&self) -> bool {
+        true
+    }
+
+    fn other_tables<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        if self.left.has_other_tables() {
+            try!(self.left.other_tables(out));
+            out.push_sql(", ");
+        }
+        self.right.from_clause(out)
+    }
+
+    fn has_predicates(&self) -> bool {
+        true
+    }
+
+    fn predicates<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
+        if self.left.has_predicates() {
+            try!(self.left.predicates(out));
+            out.push_sql(" AND ");
+        }
+        self.on.to_sql(out)
     }
 }
 
+/// A join with no condition, which returns every combination of rows from the
+/// two sides.
 #[derive(Clone, Copy)]
-pub struct LeftOuterJoinSource<Left, Right> {
+pub struct CrossJoinSource<Left, Right> {
     left: Left,
     right: Right,
 }
 
-impl<Left, Right> LeftOuterJoinSource<Left, Right> {
+impl<Left, Right> CrossJoinSource<Left, Right> {
     pub fn new(left: Left, right: Right) -> Self {
-        LeftOuterJoinSource {
+        CrossJoinSource {
             left: left,
             right: right,
         }
     }
 }
 
-impl<Left, Right> QuerySource for LeftOuterJoinSource<Left, Right> where
-    Left: Table + JoinTo<Right>,
-    Right: Table,
+impl<Left, Right> QuerySource for CrossJoinSource<Left, Right> where
+    Left: QuerySource,
+    Right: QuerySource,
 {
     fn from_clause<T: QueryBuilder>(&self, out: &mut T) -> BuildQueryResult {
         try!(self.left.from_clause(out));
-        out.push_sql(" LEFT OUTER JOIN ");
-        try!(self.right.from_clause(out));
-        out.push_sql(" ON ");
-        Expression::to_sql(&self.left.join_expression(), out)
+        out.push_sql(" CROSS JOIN ");
+        self.right.from_clause(out)
     }
 }
 
-impl<Left, Right> AsQuery for LeftOuterJoinSource<Left, Right> where
-    Left: Table + JoinTo<Right>,
-    Right: Table,
-    (Left::AllColumns, Right::AllColumns): SelectableExpression<
-                                   LeftOuterJoinSource<Left, Right>,
-                                   (Left::SqlType, Nullable<Right::SqlType>),
-                               >,
+impl<Left, Right> DefaultSelection for CrossJoinSource<Left, Right> where
+    Left: DefaultSelection,
+    Right: DefaultSelection,
+    CrossJoinSource<Left, Right>: QuerySource,
+    (Left::Selection, Right::Selection): SelectableExpression<
+        CrossJoinSource<Left, Right>,
+        (Left::SqlType, Right::SqlType),
+    >,
 {
-    type SqlType = (Left::SqlType, Nullable<Right::SqlType>);
+    type SqlType = (Left::SqlType, Right::SqlType);
+    type Selection = (Left::Selection, Right::Selection);
+
+    fn default_selection(&self) -> Self::Selection {
+        (self.left.default_selection(), self.right.default_selection())
+    }
+}
+
+impl<Left, Right> AsQuery for CrossJoinSource<Left, Right> where
+    CrossJoinSource<Left, Right>: DefaultSelection,
+    SelectStatement<
+        <Self as DefaultSelection>::SqlType,
+        <Self as DefaultSelection>::Selection,
+        Self,
+    >: Query<SqlType=<Self as DefaultSelection>::SqlType>,
+{
+    type SqlType = <Self as DefaultSelection>::SqlType;
     type Query = SelectStatement<
-        (Left::SqlType, Nullable<Right::SqlType>),
-        (Left::AllColumns, Right::AllColumns),
+        <Self as DefaultSelection>::SqlType,
+        <Self as DefaultSelection>::Selection,
         Self,
     >;
 
     fn as_query(self) -> Self::Query {
-        SelectStatement::simple((Left::all_columns(), Right::all_columns()), self)
+        SelectStatement::simple(self.default_selection(), self)
+    }
+}
+
+find_join_predicate_through_join!(CrossJoinSource<Left, Right>);
+
+/// The columns loaded from a query source when no select clause is given. For
+/// joins, columns from the right side of a left outer join are nullable.
+pub trait DefaultSelection: QuerySource {
+    type SqlType: NativeSqlType;
+    type Selection: SelectableExpression<Self, Self::SqlType>;
+
+    fn default_selection(&self) -> Self::Selection;
+}
+
+impl<T: Table> DefaultSelection for T {
+    type SqlType = <T::AllColumns as Expression>::SqlType;
+    type Selection = T::AllColumns;
+
+    fn default_selection(&self) -> Self::Selection {
+        T::all_columns()
     }
 }
 
@@ -104,3 +283,217 @@ pub trait JoinTo<T: Table>: Table {
 
     fn join_expression(&self) -> Self::Predicate;
 }
+
+/// Finds the `JoinTo` relationship between `T` and one of the tables already
+/// in this query source. `Via` records which table was used, and should always
+/// be inferred.
+pub trait FindJoinPredicate<T, Via> {
+    type Predicate: Expression<SqlType=Bool>;
+
+    fn join_predicate(&self) -> Self::Predicate;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Direct;
+#[derive(Debug, Clone, Copy)]
+pub struct ViaLeft<Via>(Via);
+#[derive(Debug, Clone, Copy)]
+pub struct ViaRight<Via>(Via);
+
+impl<Tab, T> FindJoinPredicate<T, Direct> for Tab where
+    Tab: JoinTo<T>,
+    T: Table,
+{
+    type Predicate = Tab::Predicate;
+
+    fn join_predicate(&self) -> Self::Predicate {
+        self.join_expression()
+    }
+}
+
+/// A table paired with an explicit join condition, created with `.on`. Used in
+/// place of a table when joining to replace the `joinable!` predicate.
+#[derive(Debug, Clone, Copy)]
+pub struct OnClauseWrapper<Source, On> {
+    source: Source,
+    on: On,
+}
+
+impl<Source, On> OnClauseWrapper<Source, On> {
+    pub fn new(source: Source, on: On) -> Self {
+        OnClauseWrapper {
+            source: source,
+            on: on,
+        }
+    }
+}
+
+/// The right hand side of a join, and the condition used to join it. Either a
+/// table joined through `FindJoinPredicate`, or an `OnClauseWrapper`.
+pub trait JoinTarget<Source, Via> {
+    type Table: QuerySource;
+    type Predicate: Expression<SqlType=Bool>;
+
+    fn into_table_and_predicate(self, source: &Source) -> (Self::Table, Self::Predicate);
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExplicitOn;
+
+impl<Source, T, Via> JoinTarget<Source, Via> for T where
+    T: Table,
+    Source: FindJoinPredicate<T, Via>,
+{
+    type Table = T;
+    type Predicate = Source::Predicate;
+
+    fn into_table_and_predicate(self, source: &Source) -> (T, Self::Predicate) {
+        (self, source.join_predicate())
+    }
+}
+
+/// The condition may only refer to tables being joined. It is checked against
+/// a cross join of both sides, as no columns are nullable in the `ON` clause.
+impl<Source, T, On> JoinTarget<Source, ExplicitOn> for OnClauseWrapper<T, On> where
+    T: QuerySource,
+    On: Expression<SqlType=Bool> + SelectableExpression<CrossJoinSource<Source, T>> + NonAggregate,
+{
+    type Table = T;
+    type Predicate = On;
+
+    fn into_table_and_predicate(self, _source: &Source) -> (T, On) {
+        (self.source, self.on)
+    }
+}
+
+/// Describes whether the table `T` appears in this query source. Implemented
+/// for each table by `table!`, and for each pair of tables by
+/// `allow_tables_to_appear_in_same_query!`. Joins combine the presence of
+/// their two sides.
+pub trait AppearsInFromClause<T> {
+    type Presence;
+}
+
+/// The table does not appear in the query source.
+#[derive(Debug, Clone, Copy)]
+pub struct NotPresent;
+/// The table appears once in the query source, and its columns keep their type.
+#[derive(Debug, Clone, Copy)]
+pub struct Present;
+/// The table appears once on the right side of a left outer join, so its
+/// columns become nullable.
+#[derive(Debug, Clone, Copy)]
+pub struct PresentNullable;
+/// The table appears more than once, so its columns would be ambiguous.
+#[derive(Debug, Clone, Copy)]
+pub struct Ambiguous;
+
+pub trait CombinePresence<Other> {
+    type Output;
+}
+
+macro_rules! combine_presence {
+    ($($left:ident + $right:ident = $output:ident,)+) => {
+        $(impl CombinePresence<$right> for $left {
+            type Output = $output;
+        })+
+    }
+}
+
+combine_presence! {
+    NotPresent + NotPresent = NotPresent,
+    NotPresent + Present = Present,
+    NotPresent + PresentNullable = PresentNullable,
+    NotPresent + Ambiguous = Ambiguous,
+    Present + NotPresent = Present,
+    Present + Present = Ambiguous,
+    Present + PresentNullable = Ambiguous,
+    Present + Ambiguous = Ambiguous,
+    PresentNullable + NotPresent = PresentNullable,
+    PresentNullable + Present = Ambiguous,
+    PresentNullable + PresentNullable = Ambiguous,
+    PresentNullable + Ambiguous = Ambiguous,
+    Ambiguous + NotPresent = Ambiguous,
+    Ambiguous + Present = Ambiguous,
+    Ambiguous + PresentNullable = Ambiguous,
+    Ambiguous + Ambiguous = Ambiguous,
+}
+
+pub trait MakePresenceNullable {
+    type Output;
+}
+
+impl MakePresenceNullable for NotPresent { type Output = NotPresent; }
+impl MakePresenceNullable for Present { type Output = PresentNullable; }
+impl MakePresenceNullable for PresentNullable { type Output = PresentNullable; }
+impl MakePresenceNullable for Ambiguous { type Output = Ambiguous; }
+
+/// The SQL type of a column of type `ST` when selected from a table with this
+/// presence. Not implemented when the column cannot be selected.
+pub trait SelectableColumnType<ST: NativeSqlType> {
+    type SqlType: NativeSqlType;
+}
+
+impl<ST: NativeSqlType> SelectableColumnType<ST> for Present {
+    type SqlType = ST;
+}
+
+impl<ST: NativeSqlType> SelectableColumnType<ST> for PresentNullable {
+    type SqlType = Nullable<ST>;
+}
+
+pub type ColumnTypeIn<QS, T, ST> = <
+    <QS as AppearsInFromClause<T>>::Presence as SelectableColumnType<ST>
+>::SqlType;
+
+impl<Left, Right, On, T> AppearsInFromClause<T> for InnerJoinSource<Left, Right, On> where
+    Left: AppearsInFromClause<T>,
+    Right: AppearsInFromClause<T>,
+    Left::Presence: CombinePresence<Right::Presence>,
+{
+    type Presence = <Left::Presence as CombinePresence<Right::Presence>>::Output;
+}
+
+impl<Left, Right, On, T> AppearsInFromClause<T> for LeftOuterJoinSource<Left, Right, On> where
+    Left: AppearsInFromClause<T>,
+    Right: AppearsInFromClause<T>,
+    Right::Presence: MakePresenceNullable,
+    Left::Presence: CombinePresence<<Right::Presence as MakePresenceNullable>::Output>,
+{
+    type Presence = <Left::Presence as CombinePresence<
+        <Right::Presence as MakePresenceNullable>::Output,
+    >>::Output;
+}
+
+impl<Left, Right, On, T> AppearsInFromClause<T> for RightOuterJoinSource<Left, Right, On> where
+    Left: AppearsInFromClause<T>,
+    Right: AppearsInFromClause<T>,
+    Left::Presence: MakePresenceNullable,
+    <Left::Presence as MakePresenceNullable>::Output: CombinePresence<Right::Presence>,
+{
+    type Presence = <
+        <Left::Presence as MakePresenceNullable>::Output as CombinePresence<Right::Presence>
+    >::Output;
+}
+
+impl<Left, Right, On, T> AppearsInFromClause<T> for FullOuterJoinSource<Left, Right, On> where
+    Left: AppearsInFromClause<T>,
+    Right: AppearsInFromClause<T>,
+    Left::Presence: MakePresenceNullable,
+    Right::Presence: MakePresenceNullable,
+    <Left::Presence as MakePresenceNullable>::Output:
+        CombinePresence<<Right::Presence as MakePresenceNullable>::Output>,
+{
+    type Presence = <
+        <Left::Presence as MakePresenceNullable>::Output as
+        CombinePresence<<Right::Presence as MakePresenceNullable>::Output>
+    >::Output;
+}
+
+impl<Left, Right, T> AppearsInFromClause<T> for CrossJoinSource<Left, Right> where
+    Left: AppearsInFromClause<T>,
+    Right: AppearsInFromClause<T>,
+    Left::Presence: CombinePresence<Right::Presence>,
+{
+    type Presence = <Left::Presence as CombinePresence<Right::Presence>>::Output;
+}
@@ -1,9 +1,17 @@
+pub mod alias;
 pub mod filter;
 mod joins;
 
 use expression::{Expression, SelectableExpression, NonAggregate};
 use query_builder::*;
-pub use self::joins::{InnerJoinSource, LeftOuterJoinSource};
+pub use self::joins::{InnerJoinSource, LeftOuterJoinSource, RightOuterJoinSource};
+pub use self::joins::{FullOuterJoinSource, CrossJoinSource, DefaultSelection};
+pub use self::joins::{OnClauseWrapper, JoinTarget, ExplicitOn};
+pub use self::joins::{AppearsInFromClause, FindJoinPredicate, ColumnTypeIn};
+pub use self::joins::{NotPresent, Present, PresentNullable, Ambiguous};
+pub use self::joins::{CombinePresence, MakePresenceNullable, SelectableColumnType};
+pub use self::joins::{Direct, ViaLeft, ViaRight};
+pub use self::alias::{AliasSource, AliasableColumns, AliasedField};
 use types::{FromSqlRow, NativeSqlType};
 
 pub use self::joins::JoinTo;
@@ -25,35 +33,17 @@ pub trait Column: Expression {
 }
 
 pub trait Table: QuerySource + AsQuery + Sized {
-    type PrimaryKey: Column<Table=Self> + Expression + NonAggregate;
+    type PrimaryKey: Expression + NonAggregate;
     type AllColumns: SelectableExpression<Self>;
 
     fn name() -> &'static str;
     fn primary_key(&self) -> Self::PrimaryKey;
     fn all_columns() -> Self::AllColumns;
-
-    fn inner_join<T>(self, other: T) -> InnerJoinSource<Self, T> where
-        T: Table,
-        Self: JoinTo<T>,
-    {
-        InnerJoinSource::new(self, other)
-    }
-
-    fn left_outer_join<T>(self, other: T) -> LeftOuterJoinSource<Self, T> where
-        T: Table,
-        Self: JoinTo<T>,
-    {
-        LeftOuterJoinSource::new(self, other)
-    }
 }
 
 impl<T: Table> UpdateTarget for T {
     type Table = Self;
 
-    fn where_clause<B: QueryBuilder>(&self, _out: &mut B) -> BuildQueryResult {
-        Ok(())
-    }
-
     fn table(&self) -> &Self::Table {
         self
     }
@@ -8,6 +8,7 @@ use std::ffi::NulError;
 pub enum Error {
     InvalidCString(NulError),
     DatabaseError(String),
+    QueryBuilderError(String),
 }
 
 #[derive(Debug)]
@@ -58,6 +59,7 @@ impl Display for Error {
         match self {
             &Error::InvalidCString(ref nul_err) => nul_err.fmt(f),
             &Error::DatabaseError(ref s) => write!(f, "{}", &s),
+            &Error::QueryBuilderError(ref s) => write!(f, "{}", &s),
         }
     }
 }
@@ -67,6 +69,7 @@ impl StdError for Error {
         match self {
             &Error::InvalidCString(ref nul_err) => nul_err.description(),
             &Error::DatabaseError(ref s) => &s,
+            &Error::QueryBuilderError(ref s) => &s,
         }
     }
 }
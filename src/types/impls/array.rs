@@ -6,11 +6,13 @@ use std::io::Write;
 
 use Queriable;
 use super::option::UnexpectedNullError;
-use types::{self, NativeSqlType, FromSql, ToSql, Array, IsNull};
+use types::{self, NativeSqlType, FromSql, ToSql, Array, IsNull, TypeName};
 
 impl<T: NativeSqlType> NativeSqlType for Array<T> {
     fn oid() -> u32 {
         let oid = T::oid();
+        if T::array_dimensions() > 0 { return oid }
+
         if oid == types::Bool::oid() { 1000 }
         else if oid == types::SmallInt::oid() { 1005 }
         else if oid == types::Integer::oid() { 1007 }
@@ -18,13 +20,195 @@ impl<T: NativeSqlType> NativeSqlType for Array<T> {
 
         else if oid == types::Float::oid() { 1021 }
         else if oid == types::Double::oid() { 1022 }
+        else if oid == types::Numeric::oid() { 1231 }
 
         else if oid == types::VarChar::oid() { 1015 }
         else if oid == types::Text::oid() { 1009 }
 
         else if oid == types::Binary::oid() { 1001 }
+        else if oid == types::Uuid::oid() { 2951 }
+        else if oid == types::Date::oid() { 1182 }
+        else if oid == types::Time::oid() { 1183 }
+        else if oid == types::Timestamp::oid() { 1115 }
+        else if oid == types::Timestamptz::oid() { 1185 }
+        else if oid == types::Interval::oid() { 1187 }
+        else if oid == types::Json::oid() { 199 }
+        else if oid == types::Jsonb::oid() { 3807 }
+        else if oid == types::Record::<()>::oid() { 2287 }
+        else if oid == types::Range::<types::Integer>::oid() { 3905 }
+        else if oid == types::Range::<types::BigInt>::oid() { 3927 }
+        else if oid == types::Range::<types::Numeric>::oid() { 3907 }
+        else if oid == types::Range::<types::Timestamp>::oid() { 3909 }
+        else if oid == types::Range::<types::Timestamptz>::oid() { 3911 }
+        else if oid == types::Range::<types::Date>::oid() { 3913 }
         else { 0 }
     }
+
+    fn type_name() -> Option<TypeName> {
+        T::type_name().map(TypeName::array)
+    }
+
+    fn array_dimensions() -> usize {
+        T::array_dimensions() + 1
+    }
+
+    fn array_element_oid() -> u32 {
+        T::array_element_oid()
+    }
+}
+
+/// An array of any shape. Unlike `Vec`, this can represent arrays with more
+/// than one dimension, or with lower bounds other than 1, when loaded from an
+/// `Array<ST>`. `elements` are stored in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgArray<T> {
+    pub dimensions: Vec<ArrayDimension>,
+    pub elements: Vec<T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrayDimension {
+    pub length: i32,
+    pub lower_bound: i32,
+}
+
+impl ArrayDimension {
+    pub fn new(length: i32) -> Self {
+        ArrayDimension {
+            length: length,
+            lower_bound: 1,
+        }
+    }
+}
+
+/// The binary representation of an array, with its elements still encoded.
+struct RawArray<'a> {
+    dimensions: Vec<ArrayDimension>,
+    element_oid: u32,
+    elements: Vec<Option<&'a [u8]>>,
+}
+
+impl<'a> RawArray<'a> {
+    fn from_bytes(mut bytes: &'a [u8]) -> Result<Self, Box<Error>> {
+        let num_dimensions = try!(bytes.read_i32::<BigEndian>());
+        let _has_null = try!(bytes.read_i32::<BigEndian>());
+        let element_oid = try!(bytes.read_u32::<BigEndian>());
+        if num_dimensions < 0 {
+            return Err("Received an array with a negative number of dimensions".into());
+        }
+
+        let mut dimensions = Vec::new();
+        for _ in 0..num_dimensions {
+            let length = try!(bytes.read_i32::<BigEndian>());
+            let lower_bound = try!(bytes.read_i32::<BigEndian>());
+            if length < 0 {
+                return Err("Received an array with a negative length".into());
+            }
+            dimensions.push(ArrayDimension { length: length, lower_bound: lower_bound });
+        }
+
+        let mut elements = Vec::new();
+        for _ in 0..try!(num_elements(&dimensions)) {
+            let num_bytes = try!(bytes.read_i32::<BigEndian>());
+            if num_bytes == -1 {
+                elements.push(None);
+                continue;
+            }
+            let num_bytes = num_bytes as usize;
+            if num_bytes > bytes.len() {
+                return Err("Received an array element longer than the array".into());
+            }
+            let (element, rest) = bytes.split_at(num_bytes);
+            bytes = rest;
+            elements.push(Some(element));
+        }
+
+        Ok(RawArray {
+            dimensions: dimensions,
+            element_oid: element_oid,
+            elements: elements,
+        })
+    }
+
+    fn write<W: Write>(&self, out: &mut W) -> Result<(), Box<Error>> {
+        let expected_elements = try!(num_elements(&self.dimensions));
+        if self.elements.len() != expected_elements {
+            return Err(format!("An array with dimensions {:?} must have {} elements, but it had {}",
+                self.dimensions, expected_elements, self.elements.len()).into());
+        }
+
+        let has_null = self.elements.iter().any(Option::is_none);
+        try!(out.write_i32::<BigEndian>(self.dimensions.len() as i32));
+        try!(out.write_i32::<BigEndian>(has_null as i32));
+        try!(out.write_u32::<BigEndian>(self.element_oid));
+        for dimension in &self.dimensions {
+            try!(out.write_i32::<BigEndian>(dimension.length));
+            try!(out.write_i32::<BigEndian>(dimension.lower_bound));
+        }
+        for element in &self.elements {
+            match *element {
+                Some(bytes) => {
+                    try!(out.write_i32::<BigEndian>(bytes.len() as i32));
+                    try!(out.write_all(bytes));
+                }
+                None => try!(out.write_i32::<BigEndian>(-1)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Splits a multi-dimensional array along its first dimension, encoding
+    /// each sub-array so it can be passed to `FromSql` for the inner type.
+    fn sub_arrays(&self) -> Result<Vec<Vec<u8>>, Box<Error>> {
+        let sub_dimensions = &self.dimensions[1..];
+        let sub_array_len = try!(num_elements(sub_dimensions));
+        if sub_array_len == 0 {
+            let empty_array = RawArray {
+                dimensions: Vec::new(),
+                element_oid: self.element_oid,
+                elements: Vec::new(),
+            };
+            let mut bytes = Vec::new();
+            try!(empty_array.write(&mut bytes));
+            return Ok(vec![bytes; self.dimensions[0].length as usize]);
+        }
+        self.elements.chunks(sub_array_len).map(|elements| {
+            let sub_array = RawArray {
+                dimensions: sub_dimensions.to_vec(),
+                element_oid: self.element_oid,
+                elements: elements.to_vec(),
+            };
+            let mut bytes = Vec::new();
+            try!(sub_array.write(&mut bytes));
+            Ok(bytes)
+        }).collect()
+    }
+}
+
+fn num_elements(dimensions: &[ArrayDimension]) -> Result<usize, Box<Error>> {
+    if dimensions.is_empty() {
+        return Ok(0);
+    }
+    dimensions.iter()
+        .fold(Some(1usize), |total, d| total.and_then(|total| total.checked_mul(d.length as usize)))
+        .ok_or_else(|| format!("An array with dimensions {:?} has too many elements", dimensions).into())
+}
+
+fn encode_elements<ST, T>(elements: &[T]) -> Result<Vec<Option<Vec<u8>>>, Box<Error>> where
+    ST: NativeSqlType,
+    T: ToSql<ST>,
+{
+    elements.iter().map(|element| {
+        let mut buffer = Vec::new();
+        match try!(element.to_sql(&mut buffer)) {
+            IsNull::Yes => Ok(None),
+            IsNull::No => Ok(Some(buffer)),
+        }
+    }).collect()
+}
+
+fn borrow_elements(elements: &[Option<Vec<u8>>]) -> Vec<Option<&[u8]>> {
+    elements.iter().map(|e| e.as_ref().map(|bytes| &bytes[..])).collect()
 }
 
 impl<T, ST> FromSql<Array<ST>> for Vec<T> where
@@ -32,31 +216,27 @@ impl<T, ST> FromSql<Array<ST>> for Vec<T> where
     ST: NativeSqlType,
 {
     fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
-        let mut bytes = not_none!(bytes);
-        let num_dimensions = try!(bytes.read_i32::<BigEndian>());
-        let has_null = try!(bytes.read_i32::<BigEndian>()) != 0;
-        let _oid = try!(bytes.read_i32::<BigEndian>());
-
-        if num_dimensions == 0 {
+        let array = try!(RawArray::from_bytes(not_none!(bytes)));
+        if array.dimensions.is_empty() {
             return Ok(Vec::new())
         }
 
-        let num_elements = try!(bytes.read_i32::<BigEndian>());
-        let lower_bound = try!(bytes.read_i32::<BigEndian>());
-
-        assert!(num_dimensions == 1, "multi-dimensional arrays are not supported");
-        assert!(lower_bound == 1, "lower bound must be 1");
+        let expected_dimensions = Array::<ST>::array_dimensions();
+        if array.dimensions.len() != expected_dimensions {
+            return Err(format!("Expected an array with {} dimension(s), but it had {}",
+                expected_dimensions, array.dimensions.len()).into());
+        }
+        if array.dimensions.iter().any(|d| d.lower_bound != 1) {
+            return Err("Arrays with lower bounds other than 1 must be loaded into a PgArray".into());
+        }
 
-        (0..num_elements).map(|_| {
-            let elem_size = try!(bytes.read_i32::<BigEndian>());
-            if has_null && elem_size == -1 {
-                T::from_sql(None)
-            } else {
-                let (elem_bytes, new_bytes) = bytes.split_at(elem_size as usize);
-                bytes = new_bytes;
-                T::from_sql(Some(&elem_bytes))
-            }
-        }).collect()
+        if expected_dimensions == 1 {
+            array.elements.into_iter().map(T::from_sql).collect()
+        } else {
+            try!(array.sub_arrays()).iter()
+                .map(|sub_array| T::from_sql(Some(sub_array)))
+                .collect()
+        }
     }
 }
 
@@ -70,6 +250,30 @@ impl<T, ST> Queriable<Array<ST>> for Vec<T> where
     }
 }
 
+impl<T, ST> FromSql<Array<ST>> for PgArray<T> where
+    T: FromSql<ST>,
+    ST: NativeSqlType,
+{
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+        let array = try!(RawArray::from_bytes(not_none!(bytes)));
+        let elements = try!(array.elements.into_iter().map(T::from_sql).collect());
+        Ok(PgArray {
+            dimensions: array.dimensions,
+            elements: elements,
+        })
+    }
+}
+
+impl<T, ST> Queriable<Array<ST>> for PgArray<T> where
+    T: FromSql<ST>,
+    ST: NativeSqlType,
+{
+    type Row = Self;
+    fn build(row: Self) -> Self {
+        row
+    }
+}
+
 use expression::AsExpression;
 use expression::bound::Bound;
 
@@ -95,29 +299,70 @@ impl<ST, T> AsExpression<Array<ST>> for Vec<T> where
     }
 }
 
+impl<ST, T> AsExpression<Array<ST>> for PgArray<T> where
+    ST: NativeSqlType,
+    T: ToSql<ST>,
+{
+    type Expression = Bound<Array<ST>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a, ST, T> AsExpression<Array<ST>> for &'a PgArray<T> where
+    ST: NativeSqlType,
+    T: ToSql<ST>,
+{
+    type Expression = Bound<Array<ST>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
 impl<'a, ST, T> ToSql<Array<ST>> for &'a [T] where
     ST: NativeSqlType,
     T: ToSql<ST>,
 {
     fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
-        let num_dimensions = 1;
-        try!(out.write_i32::<BigEndian>(num_dimensions));
-        let flags = 0;
-        try!(out.write_i32::<BigEndian>(flags));
-        try!(out.write_u32::<BigEndian>(ST::oid()));
-        try!(out.write_i32::<BigEndian>(self.len() as i32));
-        let lower_bound = 1;
-        try!(out.write_i32::<BigEndian>(lower_bound));
+        let elements = try!(encode_elements::<ST, T>(self));
+        if ST::array_dimensions() == 0 {
+            let array = RawArray {
+                dimensions: vec![ArrayDimension::new(self.len() as i32)],
+                element_oid: ST::array_element_oid(),
+                elements: borrow_elements(&elements),
+            };
+            try!(array.write(out));
+            return Ok(IsNull::No);
+        }
 
-        let mut buffer = Vec::new();
-        for elem in self.iter() {
-            let is_null = try!(elem.to_sql(&mut buffer));
-            assert!(is_null == IsNull::No, "Arrays containing null are not supported");
-            try!(out.write_i32::<BigEndian>(buffer.len() as i32));
-            try!(out.write_all(&buffer));
-            buffer.clear();
+        // Each element is itself an array, which must be flattened into this one
+        let mut sub_arrays = Vec::with_capacity(elements.len());
+        for element in &elements {
+            match *element {
+                Some(ref bytes) => sub_arrays.push(try!(RawArray::from_bytes(bytes))),
+                None => return Err("Multi-dimensional arrays cannot contain null sub-arrays".into()),
+            }
+        }
+        let sub_dimensions = sub_arrays.first()
+            .map(|a| a.dimensions.clone())
+            .unwrap_or_default();
+        if sub_arrays.iter().any(|a| a.dimensions != sub_dimensions) {
+            return Err("The sub-arrays of a multi-dimensional array must all have the same dimensions".into());
         }
 
+        let mut dimensions = Vec::new();
+        if !sub_dimensions.is_empty() {
+            dimensions.push(ArrayDimension::new(self.len() as i32));
+            dimensions.extend(sub_dimensions);
+        }
+        let array = RawArray {
+            dimensions: dimensions,
+            element_oid: ST::array_element_oid(),
+            elements: sub_arrays.into_iter().flat_map(|a| a.elements).collect(),
+        };
+        try!(array.write(out));
         Ok(IsNull::No)
     }
 }
@@ -130,3 +375,19 @@ impl<ST, T> ToSql<Array<ST>> for Vec<T> where
         (&self as &[T]).to_sql(out)
     }
 }
+
+impl<ST, T> ToSql<Array<ST>> for PgArray<T> where
+    ST: NativeSqlType,
+    T: ToSql<ST>,
+{
+    fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+        let elements = try!(encode_elements::<ST, T>(&self.elements));
+        let array = RawArray {
+            dimensions: self.dimensions.clone(),
+            element_oid: ST::array_element_oid(),
+            elements: borrow_elements(&elements),
+        };
+        try!(array.write(out));
+        Ok(IsNull::No)
+    }
+}
@@ -59,6 +59,7 @@ primitive_impls! {
     Interval -> (PgInterval, 1186),
     Time -> (PgTime, 1083),
     Timestamp -> (PgTimestamp, 1114),
+    Timestamptz -> (PgTimestamp, 1184),
 }
 
 impl ToSql<types::Timestamp> for PgTimestamp {
@@ -74,6 +75,18 @@ impl FromSql<types::Timestamp> for PgTimestamp {
     }
 }
 
+impl ToSql<types::Timestamptz> for PgTimestamp {
+    fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+        ToSql::<types::Timestamp>::to_sql(self, out)
+    }
+}
+
+impl FromSql<types::Timestamptz> for PgTimestamp {
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+        FromSql::<types::Timestamp>::from_sql(bytes)
+    }
+}
+
 impl ToSql<types::Date> for PgDate {
     fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
         ToSql::<types::Integer>::to_sql(&self.0, out)
@@ -131,3 +144,228 @@ impl Add<PgInterval> for PgInterval {
         }
     }
 }
+
+#[cfg(feature = "chrono")]
+mod chrono {
+    extern crate chrono;
+
+    use self::chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+    use std::error::Error;
+    use std::io::Write;
+
+    use expression::*;
+    use expression::bound::Bound;
+    use query_source::Queriable;
+    use super::{PgDate, PgTime, PgTimestamp};
+    use types::{self, FromSql, ToSql, IsNull};
+
+    queriable_impls! {
+        Timestamp -> NaiveDateTime,
+        Timestamptz -> DateTime<Utc>,
+        Date -> NaiveDate,
+        Time -> NaiveTime,
+    }
+
+    expression_impls! {
+        Timestamp -> NaiveDateTime,
+        Timestamptz -> DateTime<Utc>,
+        Date -> NaiveDate,
+        Time -> NaiveTime,
+    }
+
+    // Postgres stores timestamps and dates relative to 2000-01-01, and uses the
+    // largest and smallest values of the underlying integer for infinity.
+    fn pg_epoch_date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()
+    }
+
+    fn pg_epoch() -> NaiveDateTime {
+        pg_epoch_date().and_hms_opt(0, 0, 0).unwrap()
+    }
+
+    fn midnight() -> NaiveTime {
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    }
+
+    fn out_of_range(type_name: &str) -> Box<Error> {
+        format!("Value is out of range for {}", type_name).into()
+    }
+
+    impl FromSql<types::Timestamp> for NaiveDateTime {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            let PgTimestamp(offset) = try!(FromSql::<types::Timestamp>::from_sql(bytes));
+            if offset == i64::max_value() || offset == i64::min_value() {
+                return Err("Infinite timestamps cannot be represented as a NaiveDateTime".into());
+            }
+            pg_epoch().checked_add_signed(Duration::microseconds(offset))
+                .ok_or_else(|| out_of_range("NaiveDateTime"))
+        }
+    }
+
+    impl ToSql<types::Timestamp> for NaiveDateTime {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            let offset = try!(self.signed_duration_since(pg_epoch()).num_microseconds()
+                .ok_or_else(|| out_of_range("a timestamp")));
+            ToSql::<types::Timestamp>::to_sql(&PgTimestamp(offset), out)
+        }
+    }
+
+    impl FromSql<types::Timestamptz> for DateTime<Utc> {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            let naive = try!(<NaiveDateTime as FromSql<types::Timestamp>>::from_sql(bytes));
+            Ok(Utc.from_utc_datetime(&naive))
+        }
+    }
+
+    impl ToSql<types::Timestamptz> for DateTime<Utc> {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            ToSql::<types::Timestamp>::to_sql(&self.naive_utc(), out)
+        }
+    }
+
+    impl FromSql<types::Date> for NaiveDate {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            let PgDate(offset) = try!(FromSql::<types::Date>::from_sql(bytes));
+            if offset == i32::max_value() || offset == i32::min_value() {
+                return Err("Infinite dates cannot be represented as a NaiveDate".into());
+            }
+            pg_epoch_date().checked_add_signed(Duration::days(offset as i64))
+                .ok_or_else(|| out_of_range("NaiveDate"))
+        }
+    }
+
+    impl ToSql<types::Date> for NaiveDate {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            let offset = self.signed_duration_since(pg_epoch_date()).num_days();
+            ToSql::<types::Date>::to_sql(&PgDate(offset as i32), out)
+        }
+    }
+
+    impl FromSql<types::Time> for NaiveTime {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            let PgTime(offset) = try!(FromSql::<types::Time>::from_sql(bytes));
+            let (time, overflow) = midnight().overflowing_add_signed(Duration::microseconds(offset));
+            if overflow == 0 {
+                Ok(time)
+            } else {
+                Err(out_of_range("NaiveTime"))
+            }
+        }
+    }
+
+    impl ToSql<types::Time> for NaiveTime {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            let offset = self.signed_duration_since(midnight()).num_microseconds().unwrap();
+            ToSql::<types::Time>::to_sql(&PgTime(offset), out)
+        }
+    }
+}
+
+mod std_time {
+    use std::error::Error;
+    use std::io::Write;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use expression::*;
+    use expression::bound::Bound;
+    use query_source::Queriable;
+    use super::{PgInterval, PgTimestamp};
+    use types::{self, FromSql, ToSql, IsNull};
+
+    queriable_impls! {
+        Timestamp -> SystemTime,
+        Timestamptz -> SystemTime,
+        Interval -> Duration,
+    }
+
+    expression_impls! {
+        Timestamp -> SystemTime,
+        Timestamptz -> SystemTime,
+        Interval -> Duration,
+    }
+
+    const MICROSECONDS_PER_SECOND: u64 = 1_000_000;
+    const NANOSECONDS_PER_MICROSECOND: u32 = 1_000;
+
+    // 2000-01-01, the epoch Postgres stores timestamps relative to
+    fn pg_epoch() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(946_684_800)
+    }
+
+    fn duration_from_microseconds(microseconds: u64) -> Duration {
+        let seconds = microseconds / MICROSECONDS_PER_SECOND;
+        let subsec_micros = (microseconds % MICROSECONDS_PER_SECOND) as u32;
+        Duration::new(seconds, subsec_micros * NANOSECONDS_PER_MICROSECOND)
+    }
+
+    fn duration_to_microseconds(duration: Duration) -> Option<i64> {
+        let subsec_micros = duration.subsec_nanos() / NANOSECONDS_PER_MICROSECOND;
+        duration.as_secs().checked_mul(MICROSECONDS_PER_SECOND)
+            .and_then(|micros| micros.checked_add(subsec_micros as u64))
+            .and_then(|micros| if micros > i64::max_value() as u64 {
+                None
+            } else {
+                Some(micros as i64)
+            })
+    }
+
+    impl FromSql<types::Timestamp> for SystemTime {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            let PgTimestamp(offset) = try!(FromSql::<types::Timestamp>::from_sql(bytes));
+            if offset == i64::max_value() || offset == i64::min_value() {
+                return Err("Infinite timestamps cannot be represented as a SystemTime".into());
+            }
+            let time = if offset >= 0 {
+                pg_epoch().checked_add(duration_from_microseconds(offset as u64))
+            } else {
+                pg_epoch().checked_sub(duration_from_microseconds(offset.wrapping_neg() as u64))
+            };
+            time.ok_or_else(|| "SystemTime is out of range for a timestamp".into())
+        }
+    }
+
+    impl ToSql<types::Timestamp> for SystemTime {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            let offset = match self.duration_since(pg_epoch()) {
+                Ok(after) => duration_to_microseconds(after),
+                Err(before) => duration_to_microseconds(before.duration()).map(|micros| -micros),
+            };
+            let offset = try!(offset.ok_or("SystemTime is out of range for a timestamp"));
+            ToSql::<types::Timestamp>::to_sql(&PgTimestamp(offset), out)
+        }
+    }
+
+    impl FromSql<types::Timestamptz> for SystemTime {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            FromSql::<types::Timestamp>::from_sql(bytes)
+        }
+    }
+
+    impl ToSql<types::Timestamptz> for SystemTime {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            ToSql::<types::Timestamp>::to_sql(self, out)
+        }
+    }
+
+    impl FromSql<types::Interval> for Duration {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            let interval: PgInterval = try!(FromSql::<types::Interval>::from_sql(bytes));
+            if interval.days != 0 || interval.months != 0 {
+                return Err("Intervals with days or months cannot be represented as a Duration, \
+                    as their length is not fixed".into());
+            }
+            if interval.microseconds < 0 {
+                return Err("Negative intervals cannot be represented as a Duration".into());
+            }
+            Ok(duration_from_microseconds(interval.microseconds as u64))
+        }
+    }
+
+    impl ToSql<types::Interval> for Duration {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            let microseconds = try!(duration_to_microseconds(*self)
+                .ok_or("Duration is out of range for an interval"));
+            ToSql::<types::Interval>::to_sql(&PgInterval::from_microseconds(microseconds), out)
+        }
+    }
+}
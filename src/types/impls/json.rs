@@ -0,0 +1,96 @@
+use types::{self, NativeSqlType, Nullable};
+
+impl NativeSqlType for types::Json {
+    fn oid() -> u32 {
+        114
+    }
+}
+
+impl NativeSqlType for types::Jsonb {
+    fn oid() -> u32 {
+        3802
+    }
+}
+
+/// The SQL types which can be used with the JSON operators. `NotNull` is the
+/// type of the values retrieved from a document, before being made nullable.
+pub trait JsonType: NativeSqlType {
+    type NotNull: NativeSqlType;
+}
+
+impl JsonType for types::Json {
+    type NotNull = types::Json;
+}
+
+impl JsonType for types::Jsonb {
+    type NotNull = types::Jsonb;
+}
+
+impl<T: JsonType> JsonType for Nullable<T> {
+    type NotNull = T::NotNull;
+}
+
+/// The SQL types which can be used with the operators only defined for `jsonb`.
+pub trait JsonbType: JsonType {}
+
+impl JsonbType for types::Jsonb {}
+impl JsonbType for Nullable<types::Jsonb> {}
+
+#[cfg(feature = "serde_json")]
+mod serde_json {
+    extern crate serde_json;
+
+    use self::serde_json::Value;
+    use std::error::Error;
+    use std::io::Write;
+
+    use expression::*;
+    use expression::bound::Bound;
+    use query_source::Queriable;
+    use super::super::option::UnexpectedNullError;
+    use types::{self, FromSql, ToSql, IsNull};
+
+    const JSONB_VERSION: u8 = 1;
+
+    queriable_impls! {
+        Json -> Value,
+        Jsonb -> Value,
+    }
+
+    expression_impls! {
+        Json -> Value,
+        Jsonb -> Value,
+    }
+
+    impl FromSql<types::Json> for Value {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            let bytes = not_none!(bytes);
+            serde_json::from_slice(bytes).map_err(|e| Box::new(e) as Box<Error>)
+        }
+    }
+
+    impl ToSql<types::Json> for Value {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            serde_json::to_writer(out, self)
+                .map(|_| IsNull::No)
+                .map_err(|e| Box::new(e) as Box<Error>)
+        }
+    }
+
+    impl FromSql<types::Jsonb> for Value {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            let bytes = not_none!(bytes);
+            match bytes.split_first() {
+                Some((&JSONB_VERSION, document)) => FromSql::<types::Json>::from_sql(Some(document)),
+                _ => Err("Unsupported JSONB encoding version".into()),
+            }
+        }
+    }
+
+    impl ToSql<types::Jsonb> for Value {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            try!(out.write_all(&[JSONB_VERSION]));
+            ToSql::<types::Json>::to_sql(self, out)
+        }
+    }
+}
@@ -53,6 +53,20 @@ macro_rules! expression_impls {
     }
 }
 
+macro_rules! queriable_impls {
+    ($($Source:ident -> $Target:ty),+,) => {
+        $(
+            impl Queriable<types::$Source> for $Target {
+                type Row = Self;
+
+                fn build(row: Self::Row) -> Self {
+                    row
+                }
+            }
+        )+
+    }
+}
+
 macro_rules! primitive_impls {
     ($($Source:ident -> ($Target:ty, $oid:expr)),+,) => {
         $(
@@ -62,22 +76,21 @@ macro_rules! primitive_impls {
                 }
             }
 
-            impl Queriable<types::$Source> for $Target {
-                type Row = Self;
-
-                fn build(row: Self::Row) -> Self {
-                    row
-                }
-            }
         )+
+        queriable_impls!($($Source -> $Target),+,);
         expression_impls!($($Source -> $Target),+,);
     }
 }
 
-mod array;
+pub mod array;
 pub mod date_and_time;
 mod floats;
 mod integers;
+pub mod json;
+pub mod numeric;
 mod option;
 mod primitives;
+pub mod range;
+pub mod record;
 mod tuples;
+pub mod uuid;
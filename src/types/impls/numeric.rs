@@ -0,0 +1,174 @@
+extern crate byteorder;
+
+use self::byteorder::{ReadBytesExt, WriteBytesExt, BigEndian};
+use std::error::Error;
+use std::io::Write;
+
+use expression::*;
+use expression::bound::Bound;
+use query_source::Queriable;
+use super::option::UnexpectedNullError;
+use types::{self, NativeSqlType, FromSql, ToSql, IsNull};
+
+/// Numeric values are sent by Postgres as a list of base 10000 digits. `weight`
+/// is the power of 10000 of the first digit, and `scale` is the number of
+/// decimal digits shown after the decimal point. Trailing zero digits are
+/// omitted, so `digits` may be shorter than `weight + 1`. This struct is a dumb
+/// wrapper around that representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PgNumeric {
+    Positive {
+        weight: i16,
+        scale: u16,
+        digits: Vec<i16>,
+    },
+    Negative {
+        weight: i16,
+        scale: u16,
+        digits: Vec<i16>,
+    },
+    NaN,
+}
+
+const POSITIVE: u16 = 0x0000;
+const NEGATIVE: u16 = 0x4000;
+const NAN: u16 = 0xC000;
+
+primitive_impls! {
+    Numeric -> (PgNumeric, 1700),
+}
+
+impl FromSql<types::Numeric> for PgNumeric {
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+        let mut bytes = not_none!(bytes);
+        let num_digits = try!(bytes.read_u16::<BigEndian>());
+        let weight = try!(bytes.read_i16::<BigEndian>());
+        let sign = try!(bytes.read_u16::<BigEndian>());
+        let scale = try!(bytes.read_u16::<BigEndian>());
+        let digits = try!((0..num_digits)
+            .map(|_| bytes.read_i16::<BigEndian>())
+            .collect());
+
+        match sign {
+            POSITIVE => Ok(PgNumeric::Positive { weight: weight, scale: scale, digits: digits }),
+            NEGATIVE => Ok(PgNumeric::Negative { weight: weight, scale: scale, digits: digits }),
+            NAN => Ok(PgNumeric::NaN),
+            _ => Err(format!("Received an invalid sign for a numeric: {:x}", sign).into()),
+        }
+    }
+}
+
+impl ToSql<types::Numeric> for PgNumeric {
+    fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+        let (sign, weight, scale, digits) = match *self {
+            PgNumeric::Positive { weight, scale, ref digits } => (POSITIVE, weight, scale, &digits[..]),
+            PgNumeric::Negative { weight, scale, ref digits } => (NEGATIVE, weight, scale, &digits[..]),
+            PgNumeric::NaN => (NAN, 0, 0, &[][..]),
+        };
+        if digits.len() > u16::max_value() as usize {
+            return Err("Value is out of range for Numeric".into());
+        }
+        try!(out.write_u16::<BigEndian>(digits.len() as u16));
+        try!(out.write_i16::<BigEndian>(weight));
+        try!(out.write_u16::<BigEndian>(sign));
+        try!(out.write_u16::<BigEndian>(scale));
+        for digit in digits {
+            try!(out.write_i16::<BigEndian>(*digit));
+        }
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "numeric")]
+mod bigdecimal {
+    extern crate bigdecimal;
+    extern crate num_bigint;
+    extern crate num_traits;
+
+    use self::bigdecimal::BigDecimal;
+    use self::num_bigint::{BigInt, BigUint, Sign};
+    use self::num_traits::{pow, Signed, ToPrimitive, Zero};
+    use std::error::Error;
+    use std::io::Write;
+
+    use expression::*;
+    use expression::bound::Bound;
+    use query_source::Queriable;
+    use super::PgNumeric;
+    use types::{self, FromSql, ToSql, IsNull};
+
+    fn out_of_range() -> Box<Error> {
+        "Value is out of range for Numeric".into()
+    }
+
+    fn to_pg_numeric(decimal: &BigDecimal) -> Result<PgNumeric, Box<Error>> {
+        let (mut integer, mut exponent) = decimal.as_bigint_and_exponent();
+        if exponent < 0 {
+            integer = integer * pow(BigInt::from(10), -exponent as usize);
+            exponent = 0;
+        }
+        let scale = try!(exponent.to_u16().ok_or_else(out_of_range));
+
+        // Pad the value so the decimal point falls between two base 10000 digits
+        let padding = (4 - exponent % 4) % 4;
+        let integer = integer * pow(BigInt::from(10), padding as usize);
+        let fractional_digits = (exponent + padding) / 4;
+
+        let ten_thousand = BigUint::from(10000u32);
+        let mut remaining = integer.abs().to_biguint().unwrap();
+        let mut digits = Vec::new();
+        while !remaining.is_zero() {
+            digits.push((&remaining % &ten_thousand).to_i16().unwrap());
+            remaining = remaining / &ten_thousand;
+        }
+        digits.reverse();
+        let weight = digits.len() as i64 - fractional_digits - 1;
+        let weight = try!(weight.to_i16().ok_or_else(out_of_range));
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        let weight = if digits.is_empty() { 0 } else { weight };
+
+        match decimal.sign() {
+            Sign::Minus => Ok(PgNumeric::Negative { weight: weight, scale: scale, digits: digits }),
+            _ => Ok(PgNumeric::Positive { weight: weight, scale: scale, digits: digits }),
+        }
+    }
+
+    fn to_big_decimal(numeric: PgNumeric) -> Option<BigDecimal> {
+        let (sign, weight, scale, digits) = match numeric {
+            PgNumeric::Positive { weight, scale, digits } => (Sign::Plus, weight, scale, digits),
+            PgNumeric::Negative { weight, scale, digits } => (Sign::Minus, weight, scale, digits),
+            PgNumeric::NaN => return None,
+        };
+        let integer = digits.iter().fold(BigUint::zero(), |acc, &digit| {
+            acc * BigUint::from(10000u32) + BigUint::from(digit as u32)
+        });
+        let exponent = 4 * (digits.len() as i64 - weight as i64 - 1);
+        let decimal = BigDecimal::new(BigInt::from_biguint(sign, integer), exponent);
+        Some(decimal.with_scale(scale as i64))
+    }
+
+    queriable_impls! {
+        Numeric -> BigDecimal,
+    }
+
+    expression_impls! {
+        Numeric -> BigDecimal,
+    }
+
+    impl FromSql<types::Numeric> for BigDecimal {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            let numeric = try!(PgNumeric::from_sql(bytes));
+            to_big_decimal(numeric)
+                .ok_or_else(|| "NaN cannot be represented as a BigDecimal".into())
+        }
+    }
+
+    impl ToSql<types::Numeric> for BigDecimal {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            let numeric = try!(to_pg_numeric(self));
+            ToSql::<types::Numeric>::to_sql(&numeric, out)
+        }
+    }
+}
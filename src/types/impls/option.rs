@@ -4,12 +4,24 @@ use expression::bound::Bound;
 use std::error::Error;
 use std::fmt;
 use std::io::Write;
-use types::{NativeSqlType, FromSql, FromSqlRow, Nullable, ToSql, IsNull};
+use types::{NativeSqlType, FromSql, FromSqlRow, Nullable, ToSql, IsNull, TypeName};
 
 impl<T: NativeSqlType> NativeSqlType for Nullable<T> {
     fn oid() -> u32 {
         T::oid()
     }
+
+    fn type_name() -> Option<TypeName> {
+        T::type_name()
+    }
+
+    fn array_dimensions() -> usize {
+        T::array_dimensions()
+    }
+
+    fn array_element_oid() -> u32 {
+        T::array_element_oid()
+    }
 }
 
 impl<T, ST> FromSql<Nullable<ST>> for Option<T> where
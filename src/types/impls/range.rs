@@ -0,0 +1,229 @@
+extern crate byteorder;
+
+use self::byteorder::{ReadBytesExt, WriteBytesExt, BigEndian};
+use std::error::Error;
+use std::io::Write;
+
+use expression::AsExpression;
+use expression::bound::Bound;
+use query_source::Queriable;
+use super::option::UnexpectedNullError;
+use types::{self, NativeSqlType, FromSql, ToSql, IsNull, Nullable, Range};
+
+/// The SQL types which can be used with the range operators. `Subtype` is the
+/// type of the elements of the range.
+pub trait RangeType: NativeSqlType {
+    type Subtype: NativeSqlType;
+}
+
+impl<ST: NativeSqlType> RangeType for Range<ST> {
+    type Subtype = ST;
+}
+
+impl<T: RangeType> RangeType for Nullable<T> {
+    type Subtype = T::Subtype;
+}
+
+impl<ST: NativeSqlType> NativeSqlType for Range<ST> {
+    fn oid() -> u32 {
+        let oid = ST::oid();
+        if oid == types::Integer::oid() { 3904 }
+        else if oid == types::BigInt::oid() { 3926 }
+        else if oid == types::Numeric::oid() { 3906 }
+        else if oid == types::Timestamp::oid() { 3908 }
+        else if oid == types::Timestamptz::oid() { 3910 }
+        else if oid == types::Date::oid() { 3912 }
+        else { 0 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeBound<T> {
+    Inclusive(T),
+    Exclusive(T),
+    Unbounded,
+}
+
+/// Postgres normalizes ranges over discrete types such as `int4range` or
+/// `daterange` to have an inclusive lower bound and an exclusive upper bound,
+/// so a range may not be returned with the same bounds it was sent with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PgRange<T> {
+    Empty,
+    NonEmpty {
+        lower: RangeBound<T>,
+        upper: RangeBound<T>,
+    },
+}
+
+impl<T> PgRange<T> {
+    pub fn new(lower: RangeBound<T>, upper: RangeBound<T>) -> Self {
+        PgRange::NonEmpty {
+            lower: lower,
+            upper: upper,
+        }
+    }
+}
+
+impl<T> From<::std::ops::Range<T>> for PgRange<T> {
+    fn from(range: ::std::ops::Range<T>) -> Self {
+        PgRange::new(RangeBound::Inclusive(range.start), RangeBound::Exclusive(range.end))
+    }
+}
+
+const EMPTY: u8 = 0x01;
+const LOWER_INCLUSIVE: u8 = 0x02;
+const UPPER_INCLUSIVE: u8 = 0x04;
+const LOWER_UNBOUNDED: u8 = 0x08;
+const UPPER_UNBOUNDED: u8 = 0x10;
+
+impl<T, ST> FromSql<Range<ST>> for PgRange<T> where
+    T: FromSql<ST>,
+    ST: NativeSqlType,
+{
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+        let mut bytes = not_none!(bytes);
+        let flags = try!(bytes.read_u8());
+        if flags & EMPTY != 0 {
+            return Ok(PgRange::Empty);
+        }
+
+        let lower = try!(read_bound(&mut bytes, flags, LOWER_UNBOUNDED, LOWER_INCLUSIVE));
+        let upper = try!(read_bound(&mut bytes, flags, UPPER_UNBOUNDED, UPPER_INCLUSIVE));
+        Ok(PgRange::new(lower, upper))
+    }
+}
+
+fn read_bound<T, ST>(bytes: &mut &[u8], flags: u8, unbounded: u8, inclusive: u8)
+    -> Result<RangeBound<T>, Box<Error>> where
+    T: FromSql<ST>,
+    ST: NativeSqlType,
+{
+    if flags & unbounded != 0 {
+        return Ok(RangeBound::Unbounded);
+    }
+    let num_bytes = try!(bytes.read_i32::<BigEndian>()) as usize;
+    if num_bytes > bytes.len() {
+        return Err("Received a range bound longer than the range".into());
+    }
+    let (value_bytes, rest) = bytes.split_at(num_bytes);
+    *bytes = rest;
+    let value = try!(T::from_sql(Some(value_bytes)));
+    if flags & inclusive != 0 {
+        Ok(RangeBound::Inclusive(value))
+    } else {
+        Ok(RangeBound::Exclusive(value))
+    }
+}
+
+impl<T, ST> ToSql<Range<ST>> for PgRange<T> where
+    T: ToSql<ST>,
+    ST: NativeSqlType,
+{
+    fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+        let (lower, upper) = match *self {
+            PgRange::Empty => {
+                try!(out.write_u8(EMPTY));
+                return Ok(IsNull::No);
+            }
+            PgRange::NonEmpty { ref lower, ref upper } => (lower, upper),
+        };
+
+        let flags = bound_flags(lower, LOWER_UNBOUNDED, LOWER_INCLUSIVE)
+            | bound_flags(upper, UPPER_UNBOUNDED, UPPER_INCLUSIVE);
+        try!(out.write_u8(flags));
+        try!(write_bound::<T, ST, W>(lower, out));
+        try!(write_bound::<T, ST, W>(upper, out));
+        Ok(IsNull::No)
+    }
+}
+
+fn bound_flags<T>(bound: &RangeBound<T>, unbounded: u8, inclusive: u8) -> u8 {
+    match *bound {
+        RangeBound::Inclusive(_) => inclusive,
+        RangeBound::Exclusive(_) => 0,
+        RangeBound::Unbounded => unbounded,
+    }
+}
+
+fn write_bound<T, ST, W>(bound: &RangeBound<T>, out: &mut W) -> Result<(), Box<Error>> where
+    T: ToSql<ST>,
+    ST: NativeSqlType,
+    W: Write,
+{
+    let value = match *bound {
+        RangeBound::Inclusive(ref value) | RangeBound::Exclusive(ref value) => value,
+        RangeBound::Unbounded => return Ok(()),
+    };
+    let mut buffer = Vec::new();
+    if try!(value.to_sql(&mut buffer)) == IsNull::Yes {
+        return Err("The bounds of a range cannot be null".into());
+    }
+    try!(out.write_i32::<BigEndian>(buffer.len() as i32));
+    try!(out.write_all(&buffer));
+    Ok(())
+}
+
+impl<T, ST> ToSql<Nullable<Range<ST>>> for PgRange<T> where
+    T: ToSql<ST>,
+    ST: NativeSqlType,
+{
+    fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+        ToSql::<Range<ST>>::to_sql(self, out)
+    }
+}
+
+impl<T, ST> Queriable<Range<ST>> for PgRange<T> where
+    T: FromSql<ST> + Queriable<ST>,
+    ST: NativeSqlType,
+{
+    type Row = Self;
+
+    fn build(row: Self) -> Self {
+        row
+    }
+}
+
+impl<T, ST> AsExpression<Range<ST>> for PgRange<T> where
+    T: ToSql<ST>,
+    ST: NativeSqlType,
+{
+    type Expression = Bound<Range<ST>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a, T, ST> AsExpression<Range<ST>> for &'a PgRange<T> where
+    T: ToSql<ST>,
+    ST: NativeSqlType,
+{
+    type Expression = Bound<Range<ST>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<T, ST> AsExpression<Nullable<Range<ST>>> for PgRange<T> where
+    T: ToSql<ST>,
+    ST: NativeSqlType,
+{
+    type Expression = Bound<Nullable<Range<ST>>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+impl<'a, T, ST> AsExpression<Nullable<Range<ST>>> for &'a PgRange<T> where
+    T: ToSql<ST>,
+    ST: NativeSqlType,
+{
+    type Expression = Bound<Nullable<Range<ST>>, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
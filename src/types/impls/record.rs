@@ -0,0 +1,83 @@
+extern crate byteorder;
+
+use self::byteorder::{ReadBytesExt, WriteBytesExt, BigEndian};
+use std::error::Error;
+use std::io::Write;
+
+use types::{NativeSqlType, ToSql, IsNull, Record};
+
+/// SQL types which are sent in the binary record format, such as anonymous
+/// records created with `ROW(...)` or composite types created with `CREATE TYPE
+/// ... AS (...)`. `Fields` is a tuple of the SQL types of each field, in order.
+/// Tuples can be received as any composite type with matching fields, and sent
+/// as a `NamedCompositeType`.
+/// To load a composite value from a query, select it into a struct declared
+/// with `queriable!` (or a tuple field of one). A bare tuple can't be used, as
+/// the SQL type of a selected tuple is inferred from its `Queriable` impl.
+pub trait CompositeType: NativeSqlType {
+    type Fields: NativeSqlType;
+}
+
+/// Composite types which have a name in the database, declared with
+/// `sql_composite!`. Postgres can't receive a bind parameter of an anonymous
+/// record type, so only these can be sent.
+pub trait NamedCompositeType: CompositeType {
+}
+
+impl<ST: NativeSqlType> NativeSqlType for Record<ST> {
+    fn oid() -> u32 {
+        2249
+    }
+}
+
+impl<ST: NativeSqlType> CompositeType for Record<ST> {
+    type Fields = ST;
+}
+
+pub fn read_field_count(bytes: &mut &[u8], expected: usize) -> Result<(), Box<Error>> {
+    let num_fields = try!(bytes.read_i32::<BigEndian>());
+    if num_fields as usize == expected {
+        Ok(())
+    } else {
+        Err(format!("Expected a record with {} fields, but it had {}", expected, num_fields).into())
+    }
+}
+
+/// Each field is sent with its OID and length, followed by its value. A length
+/// of -1 indicates a null field.
+pub fn read_field<'a>(bytes: &mut &'a [u8]) -> Result<Option<&'a [u8]>, Box<Error>> {
+    let _oid = try!(bytes.read_u32::<BigEndian>());
+    let num_bytes = try!(bytes.read_i32::<BigEndian>());
+    if num_bytes == -1 {
+        return Ok(None);
+    }
+    let num_bytes = num_bytes as usize;
+    if num_bytes > bytes.len() {
+        return Err("Received a record field longer than the record".into());
+    }
+    let (field, rest) = bytes.split_at(num_bytes);
+    *bytes = rest;
+    Ok(Some(field))
+}
+
+pub fn write_field_count<W: Write>(out: &mut W, num_fields: usize) -> Result<(), Box<Error>> {
+    try!(out.write_i32::<BigEndian>(num_fields as i32));
+    Ok(())
+}
+
+pub fn write_field<ST, T, W>(value: &T, out: &mut W) -> Result<(), Box<Error>> where
+    ST: NativeSqlType,
+    T: ToSql<ST>,
+    W: Write,
+{
+    let mut buffer = Vec::new();
+    try!(out.write_u32::<BigEndian>(ST::oid()));
+    match try!(value.to_sql(&mut buffer)) {
+        IsNull::Yes => try!(out.write_i32::<BigEndian>(-1)),
+        IsNull::No => {
+            try!(out.write_i32::<BigEndian>(buffer.len() as i32));
+            try!(out.write_all(&buffer));
+        }
+    }
+    Ok(())
+}
@@ -1,10 +1,16 @@
-use expression::{Expression, SelectableExpression, NonAggregate};
+use expression::{Expression, SelectableExpression, NonAggregate, AsExpression};
+use expression::bound::Bound;
 use persistable::InsertableColumns;
-use query_builder::{Changeset, QueryBuilder, BuildQueryResult};
+use query_builder::{Changeset, SelectableChangeset, QueryBuilder, BuildQueryResult};
+use query_builder::set_operation::{CompoundOrder, CompoundOrderFragment, SelectedColumn};
 use query_source::QuerySource;
+use query_source::alias::AliasableColumns;
 use row::Row;
 use std::error::Error;
-use types::{NativeSqlType, FromSqlRow, ValuesToSql, Nullable};
+use std::io::Write;
+use super::option::UnexpectedNullError;
+use super::record;
+use types::{NativeSqlType, FromSql, FromSqlRow, ToSql, IsNull, ValuesToSql, Nullable, CompositeType, NamedCompositeType};
 use {Queriable, Table, Column};
 
 // FIXME(https://github.com/rust-lang/rust/issues/19630) Remove this work-around
@@ -32,6 +38,10 @@ macro_rules! tuple_impls {
                 fn build_from_row<RowT: Row>(row: &mut RowT) -> Result<Self, Box<Error>> {
                     Ok(($(try!($T::build_from_row(row))),+))
                 }
+
+                fn fields_needed() -> usize {
+                    $(<$T as FromSqlRow<$ST>>::fields_needed() +)+ 0
+                }
             }
 
             impl<$($T),+,$($ST),+> FromSqlRow<Nullable<($($ST),+)>> for Option<($($T),+)> where
@@ -39,12 +49,20 @@ macro_rules! tuple_impls {
                 $($ST: NativeSqlType),+
             {
                 fn build_from_row<RowT: Row>(row: &mut RowT) -> Result<Self, Box<Error>> {
-                    if e!(row.next_is_null($Tuple)) {
+                    let fields_needed = <Self as FromSqlRow<Nullable<($($ST),+)>>>::fields_needed();
+                    if row.next_is_null(fields_needed) {
+                        for _ in 0..fields_needed {
+                            row.take();
+                        }
                         Ok(None)
                     } else {
                         Ok(Some(($(try!($T::build_from_row(row))),+)))
                     }
                 }
+
+                fn fields_needed() -> usize {
+                    $(<$T as FromSqlRow<$ST>>::fields_needed() +)+ 0
+                }
             }
 
             impl<$($T),+,$($ST),+> ValuesToSql<($($ST),+)> for ($($T),+) where
@@ -68,6 +86,61 @@ macro_rules! tuple_impls {
                 }
             }
 
+            impl<$($T),+, $($ST),+, Comp> FromSql<Comp> for ($($T),+) where
+                Comp: CompositeType<Fields=($($ST),+)>,
+                $($T: FromSql<$ST>),+,
+                $($ST: NativeSqlType),+
+            {
+                fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+                    let mut bytes = not_none!(bytes);
+                    try!(record::read_field_count(&mut bytes, $Tuple));
+                    Ok(($(try!($T::from_sql(try!(record::read_field(&mut bytes))))),+))
+                }
+            }
+
+            impl<$($T),+, $($ST),+, Comp> ToSql<Comp> for ($($T),+) where
+                Comp: NamedCompositeType<Fields=($($ST),+)>,
+                $($T: ToSql<$ST>),+,
+                $($ST: NativeSqlType),+
+            {
+                fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+                    try!(record::write_field_count(out, $Tuple));
+                    $(try!(record::write_field::<$ST, _, _>(&e!(self.$idx), out));)+
+                    Ok(IsNull::No)
+                }
+            }
+
+            impl<$($T),+, Comp> AsExpression<Comp> for ($($T),+) where
+                Comp: NamedCompositeType,
+                ($($T),+): ToSql<Comp>,
+            {
+                type Expression = Bound<Comp, Self>;
+
+                fn as_expression(self) -> Self::Expression {
+                    Bound::new(self)
+                }
+            }
+
+            impl<$($T),+, Comp> ToSql<Nullable<Comp>> for ($($T),+) where
+                Comp: NamedCompositeType,
+                ($($T),+): ToSql<Comp>,
+            {
+                fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+                    ToSql::<Comp>::to_sql(self, out)
+                }
+            }
+
+            impl<$($T),+, Comp> AsExpression<Nullable<Comp>> for ($($T),+) where
+                Comp: NamedCompositeType,
+                ($($T),+): ToSql<Comp>,
+            {
+                type Expression = Bound<Nullable<Comp>, Self>;
+
+                fn as_expression(self) -> Self::Expression {
+                    Bound::new(self)
+                }
+            }
+
             impl<$($T: Expression + NonAggregate),+> Expression for ($($T),+) {
                 type SqlType = ($(<$T as Expression>::SqlType),+);
 
@@ -100,9 +173,32 @@ macro_rules! tuple_impls {
             impl<$($T: Column<Table=Tab>),+, Tab: Table> InsertableColumns<Tab> for ($($T),+) {
                 type SqlType = ($(<$T as Expression>::SqlType),+);
 
-                fn names(&self) -> String {
-                    let parts: &[&str] = &[$($T::name()),*];
-                    parts.join(", ")
+                fn names_to_sql<Builder: QueryBuilder>(&self, out: &mut Builder)
+                -> BuildQueryResult {
+                    $(
+                        if e!($idx) != 0 {
+                            out.push_sql(", ");
+                        }
+                        try!(e!(self.$idx.names_to_sql(out)));
+                    )+
+                    Ok(())
+                }
+            }
+
+            impl<$($T: Column<Table=Tab>),+, Tab: Table> AliasableColumns for ($($T),+) where
+                ($($T),+): Expression,
+            {
+                type Table = Tab;
+
+                fn to_sql_with_alias<Builder: QueryBuilder>(alias: &str, out: &mut Builder)
+                -> BuildQueryResult {
+                    $(
+                        if e!($idx) != 0 {
+                            out.push_sql(", ");
+                        }
+                        try!($T::to_sql_with_alias(alias, out));
+                    )+
+                    Ok(())
                 }
             }
 
@@ -129,20 +225,66 @@ macro_rules! tuple_impls {
             {
                 type Target = Target;
 
+                fn is_noop(&self) -> bool {
+                    $(e!(self.$idx.is_noop()) &&)+ true
+                }
+
                 fn to_sql<Builder: QueryBuilder>(&self, out: &mut Builder) -> BuildQueryResult {
+                    let mut needs_comma = false;
                     $(
-                        if e!($idx) != 0 {
+                        if !e!(self.$idx.is_noop()) {
+                            if needs_comma {
+                                out.push_sql(", ");
+                            }
+                            try!(e!(self.$idx.to_sql(out)));
+                            needs_comma = true;
+                        }
+                    )+
+                    Ok(())
+                }
+            }
+
+            impl<QS, $($T: SelectableChangeset<QS>),+> SelectableChangeset<QS> for ($($T),+) where
+                ($($T),+): Changeset,
+            {
+            }
+
+            impl<$($T),+, $($TT),+, Selection> CompoundOrder<Selection, ($($TT),+)> for ($($T),+) where
+                $($T: CompoundOrder<Selection, $TT>),+
+            {
+            }
+
+            impl<$($T),+, $($TT),+> CompoundOrderFragment<($($TT),+)> for ($($T),+) where
+                $($T: CompoundOrderFragment<$TT>),+
+            {
+                fn to_compound_order_sql<Builder: QueryBuilder>(&self, out: &mut Builder) -> BuildQueryResult {
+                    $(
+                        if $idx != 0 {
                             out.push_sql(", ");
                         }
-                        try!(e!(self.$idx.to_sql(out)));
+                        try!(CompoundOrderFragment::<$TT>::to_compound_order_sql(&e!(self.$idx), out));
                     )+
                     Ok(())
                 }
             }
+
+            selected_column_impls!(($($T),+) $(($idx) $T)+);
         )+
     }
 }
 
+macro_rules! selected_column_impls {
+    ($tuple:tt $(($idx:tt) $T:ident)+) => {
+        $(selected_column_impls!(@position $tuple $idx $T);)+
+    };
+    (@position ($($All:ident),+) $idx:tt $T:ident) => {
+        impl<$($All),+> SelectedColumn<($($All),+), [(); $idx]> for $T where
+            $T: Column,
+        {
+        }
+    };
+}
+
 tuple_impls! {
     2 {
         (0) -> A, SA, TA,
@@ -0,0 +1,73 @@
+use std::error::Error;
+use std::io::Write;
+
+use expression::*;
+use expression::bound::Bound;
+use query_source::Queriable;
+use super::option::UnexpectedNullError;
+use types::{self, NativeSqlType, FromSql, ToSql, IsNull};
+
+/// A UUID, stored as its 16 bytes in network order. This struct is a dumb
+/// wrapper type, meant only to indicate the meaning of the bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PgUuid(pub [u8; 16]);
+
+primitive_impls! {
+    Uuid -> (PgUuid, 2950),
+}
+
+impl FromSql<types::Uuid> for PgUuid {
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+        let bytes = not_none!(bytes);
+        if bytes.len() != 16 {
+            return Err(format!("Received {} bytes for a uuid, expected 16", bytes.len()).into());
+        }
+        let mut uuid = [0; 16];
+        uuid.copy_from_slice(bytes);
+        Ok(PgUuid(uuid))
+    }
+}
+
+impl ToSql<types::Uuid> for PgUuid {
+    fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+        out.write_all(&self.0)
+            .map(|_| IsNull::No)
+            .map_err(|e| Box::new(e) as Box<Error>)
+    }
+}
+
+#[cfg(feature = "uuid")]
+mod uuid {
+    extern crate uuid;
+
+    use self::uuid::Uuid;
+    use std::error::Error;
+    use std::io::Write;
+
+    use expression::*;
+    use expression::bound::Bound;
+    use query_source::Queriable;
+    use super::PgUuid;
+    use types::{self, FromSql, ToSql, IsNull};
+
+    queriable_impls! {
+        Uuid -> Uuid,
+    }
+
+    expression_impls! {
+        Uuid -> Uuid,
+    }
+
+    impl FromSql<types::Uuid> for Uuid {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+            let PgUuid(bytes) = try!(PgUuid::from_sql(bytes));
+            Ok(Uuid::from_bytes(bytes))
+        }
+    }
+
+    impl ToSql<types::Uuid> for Uuid {
+        fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+            ToSql::<types::Uuid>::to_sql(&PgUuid(*self.as_bytes()), out)
+        }
+    }
+}
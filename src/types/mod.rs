@@ -4,8 +4,15 @@ mod impls;
 
 pub mod structs {
     pub use super::impls::date_and_time::{PgTimestamp, PgDate, PgTime, PgInterval};
+    pub use super::impls::array::{PgArray, ArrayDimension};
+    pub use super::impls::numeric::PgNumeric;
+    pub use super::impls::range::{PgRange, RangeBound};
+    pub use super::impls::uuid::PgUuid;
 }
 
+pub use self::impls::json::{JsonType, JsonbType};
+pub use self::impls::range::RangeType;
+pub use self::impls::record::{CompositeType, NamedCompositeType};
 pub use self::ord::SqlOrd;
 
 use row::Row;
@@ -24,22 +31,76 @@ pub type BigSerial = BigInt;
 
 #[derive(Clone, Copy)] pub struct Float;
 #[derive(Clone, Copy)] pub struct Double;
+#[derive(Clone, Copy)] pub struct Numeric;
 
 #[derive(Clone, Copy)] pub struct VarChar;
 #[derive(Clone, Copy)] pub struct Text;
 
 #[derive(Clone, Copy)] pub struct Binary;
 
+#[derive(Clone, Copy)] pub struct Uuid;
+
+#[derive(Clone, Copy)] pub struct Json;
+#[derive(Clone, Copy)] pub struct Jsonb;
+
 #[derive(Clone, Copy)] pub struct Date;
 #[derive(Clone, Copy)] pub struct Interval;
 #[derive(Clone, Copy)] pub struct Time;
 #[derive(Clone, Copy)] pub struct Timestamp;
+#[derive(Clone, Copy)] pub struct Timestamptz;
 
 #[derive(Clone, Copy)] pub struct Nullable<T: NativeSqlType>(T);
 #[derive(Clone, Copy)] pub struct Array<T: NativeSqlType>(T);
+#[derive(Clone, Copy)] pub struct Range<T: NativeSqlType>(T);
+#[derive(Clone, Copy)] pub struct Record<T: NativeSqlType>(T);
 
 pub trait NativeSqlType {
     fn oid() -> u32;
+
+    /// Types whose OID is assigned when they are created in a database, such
+    /// as enums or types from extensions, return their name in `pg_type` here.
+    /// The OID is then looked up the first time the type is bound on each
+    /// connection, and `oid` is ignored.
+    fn type_name() -> Option<TypeName> {
+        None
+    }
+
+    /// Postgres doesn't distinguish between arrays with different numbers of
+    /// dimensions, so `Array<Array<Integer>>` (`int[][]`) has the same OID as
+    /// `Array<Integer>`. This is how many `Array`s deep the type is.
+    fn array_dimensions() -> usize {
+        0
+    }
+
+    /// The OID of the innermost elements of an array type, which is sent with
+    /// array values. Other types return their own OID.
+    fn array_element_oid() -> u32 {
+        Self::oid()
+    }
+}
+
+/// The name of a type whose OID differs between databases. `is_array` refers
+/// to the array type of the named type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TypeName {
+    pub name: &'static str,
+    pub is_array: bool,
+}
+
+impl TypeName {
+    pub fn new(name: &'static str) -> Self {
+        TypeName {
+            name: name,
+            is_array: false,
+        }
+    }
+
+    pub fn array(self) -> Self {
+        TypeName {
+            is_array: true,
+            ..self
+        }
+    }
 }
 
 pub trait FromSql<A: NativeSqlType>: Sized {
@@ -48,6 +109,11 @@ pub trait FromSql<A: NativeSqlType>: Sized {
 
 pub trait FromSqlRow<A: NativeSqlType>: Sized {
     fn build_from_row<T: Row>(row: &mut T) -> Result<Self, Box<Error>>;
+
+    /// The number of columns read by `build_from_row`.
+    fn fields_needed() -> usize {
+        1
+    }
 }
 
 impl<A, T> FromSqlRow<A> for T where
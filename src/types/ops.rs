@@ -46,7 +46,7 @@ macro_rules! numeric_type {
     }
 }
 
-numeric_type!(SmallInt, Integer, BigInt, Float, Double);
+numeric_type!(SmallInt, Integer, BigInt, Float, Double, Numeric);
 
 impl Add for super::Timestamp {
     type Rhs = super::Interval;
@@ -58,6 +58,16 @@ impl Sub for super::Timestamp {
     type Output = super::Timestamp;
 }
 
+impl Add for super::Timestamptz {
+    type Rhs = super::Interval;
+    type Output = super::Timestamptz;
+}
+
+impl Sub for super::Timestamptz {
+    type Rhs = super::Interval;
+    type Output = super::Timestamptz;
+}
+
 impl Add for super::Date {
     type Rhs = super::Interval;
     type Output = super::Timestamp;
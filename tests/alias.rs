@@ -0,0 +1,144 @@
+use yaqb::*;
+
+table! {
+    employees {
+        id -> Serial,
+        name -> VarChar,
+        manager_id -> Integer,
+    }
+}
+
+table! {
+    tasks {
+        id -> Serial,
+        title -> VarChar,
+        assignee_id -> Integer,
+    }
+}
+
+alias!(employees as managers);
+allow_tables_to_appear_in_same_query!(tasks, employees);
+allow_tables_to_appear_in_same_query!(tasks, alias managers);
+
+fn connection_with_employees() -> Connection {
+    let connection = ::schema::connection();
+    connection.execute("CREATE TABLE employees (
+        id SERIAL PRIMARY KEY,
+        name VARCHAR NOT NULL,
+        manager_id INTEGER NOT NULL
+    )").unwrap();
+    connection.execute("INSERT INTO employees (name, manager_id) VALUES
+        ('Boss', 0),
+        ('Sean', 1),
+        ('Tess', 2)
+    ").unwrap();
+    connection
+}
+
+#[test]
+fn select_from_alias() {
+    let connection = connection_with_employees();
+
+    let source = managers.select(managers.field(employees::name))
+        .filter(managers.field(employees::id).eq(2));
+    let expected_data = vec!["Sean".to_string()];
+    let actual_data: Vec<String> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn loading_all_columns_from_alias() {
+    let connection = connection_with_employees();
+
+    let expected_data = vec![
+        (1, "Boss".to_string(), 0),
+        (2, "Sean".to_string(), 1),
+        (3, "Tess".to_string(), 2),
+    ];
+    let actual_data: Vec<(i32, String, i32)> = managers.load(&connection)
+        .unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn self_join_with_alias() {
+    let connection = connection_with_employees();
+
+    let source = employees::table
+        .inner_join(managers.on(managers.field(employees::id).eq(employees::manager_id)))
+        .select((employees::name, managers.field(employees::name)))
+        .order(employees::id);
+    let expected_data = vec![
+        ("Sean".to_string(), "Boss".to_string()),
+        ("Tess".to_string(), "Sean".to_string()),
+    ];
+    let actual_data: Vec<(String, String)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn aliased_columns_on_right_side_of_left_outer_join_are_nullable() {
+    let connection = connection_with_employees();
+
+    let source = employees::table
+        .left_outer_join(managers.on(managers.field(employees::id).eq(employees::manager_id)))
+        .select((employees::name, managers.field(employees::name)))
+        .order(employees::id);
+    let expected_data = vec![
+        ("Boss".to_string(), None),
+        ("Sean".to_string(), Some("Boss".to_string())),
+        ("Tess".to_string(), Some("Sean".to_string())),
+    ];
+    let actual_data: Vec<(String, Option<String>)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn alias_can_be_joined_with_another_table() {
+    let connection = connection_with_employees();
+    connection.execute("CREATE TABLE tasks (
+        id SERIAL PRIMARY KEY,
+        title VARCHAR NOT NULL,
+        assignee_id INTEGER NOT NULL
+    )").unwrap();
+    connection.execute("INSERT INTO tasks (title, assignee_id) VALUES
+        ('Hire', 1),
+        ('Review', 2)
+    ").unwrap();
+
+    let source = tasks::table
+        .inner_join(managers.on(managers.field(employees::id).eq(tasks::assignee_id)))
+        .select((tasks::title, managers.field(employees::name)))
+        .order(tasks::id);
+    let expected_data = vec![
+        ("Hire".to_string(), "Boss".to_string()),
+        ("Review".to_string(), "Sean".to_string()),
+    ];
+    let actual_data: Vec<(String, String)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn alias_renders_with_its_own_name() {
+    use yaqb::query_builder::AsQuery;
+    use yaqb::query_builder::pg::PgQueryBuilder;
+
+    let connection = ::schema::connection();
+    let query = employees::table
+        .inner_join(managers.on(managers.field(employees::id).eq(employees::manager_id)))
+        .select(managers.field(employees::name))
+        .as_query();
+    let mut query_builder = PgQueryBuilder::new(&connection);
+    Expression::to_sql(&query, &mut query_builder).unwrap();
+
+    assert_eq!(
+        "SELECT \"managers\".\"name\" FROM \"employees\" INNER JOIN \"employees\" AS \"managers\" \
+        ON \"managers\".\"id\" = \"employees\".\"manager_id\"",
+        query_builder.sql
+    );
+}
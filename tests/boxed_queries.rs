@@ -0,0 +1,45 @@
+use schema::*;
+use yaqb::*;
+
+#[test]
+fn boxed_queries_can_be_filtered_conditionally() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let filter_by_name = |name_param: Option<&str>| {
+        let mut query = users.select(name).into_boxed();
+        if let Some(name_param) = name_param {
+            query = query.filter(name.eq(name_param.to_string()));
+        }
+        query.order(name).load(&connection).unwrap().collect::<Vec<String>>()
+    };
+
+    assert_eq!(vec!["Sean".to_string(), "Tess".to_string()], filter_by_name(None));
+    assert_eq!(vec!["Tess".to_string()], filter_by_name(Some("Tess")));
+}
+
+#[test]
+fn boxed_queries_keep_existing_clauses() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let query = users.filter(id.gt(0)).order(id).into_boxed()
+        .filter(name.ne("Sean"));
+    let expected_data = vec![User::new(2, "Tess")];
+    let data: Vec<User> = query.load(&connection).unwrap().collect();
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn boxed_queries_can_be_ordered_and_limited() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let mut query = users.select(name).into_boxed();
+    query = query.order(name.desc());
+    let data: Vec<String> = query.limit(1).load(&connection).unwrap().collect();
+    assert_eq!(vec!["Tess".to_string()], data);
+}
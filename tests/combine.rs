@@ -0,0 +1,130 @@
+use schema::*;
+use yaqb::*;
+
+fn connection_with_users_and_posts() -> Connection {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    setup_posts_table(&connection);
+    connection.execute("INSERT INTO posts (user_id, title) VALUES
+        (1, 'Sean'),
+        (1, 'Hello'),
+        (2, 'World')
+    ").unwrap();
+    connection
+}
+
+#[test]
+fn union() {
+    let connection = connection_with_users_and_posts();
+
+    let source = users::table.select(users::name)
+        .union(posts::table.select(posts::title))
+        .order(users::name);
+    let expected_data = vec![
+        "Hello".to_string(),
+        "Sean".to_string(),
+        "Tess".to_string(),
+        "World".to_string(),
+    ];
+    let data: Vec<String> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn union_all() {
+    let connection = connection_with_users_and_posts();
+
+    let source = users::table.select(users::name)
+        .union_all(posts::table.select(posts::title).filter(posts::user_id.eq(1)))
+        .order(users::name.desc())
+        .limit(3);
+    let expected_data = vec!["Tess".to_string(), "Sean".to_string(), "Sean".to_string()];
+    let data: Vec<String> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn intersect() {
+    let connection = connection_with_users_and_posts();
+
+    let source = users::table.select(users::name)
+        .intersect(posts::table.select(posts::title));
+    let expected_data = vec!["Sean".to_string()];
+    let data: Vec<String> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn except() {
+    let connection = connection_with_users_and_posts();
+
+    let source = users::table.select(users::name)
+        .except(posts::table.select(posts::title));
+    let expected_data = vec!["Tess".to_string()];
+    let data: Vec<String> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn combined_queries_can_be_loaded_into_structs() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let source = users::table.filter(users::id.eq(1))
+        .union(users::table.filter(users::id.eq(2)))
+        .order(users::id);
+    let expected_data = vec![User::new(1, "Sean"), User::new(2, "Tess")];
+    let data: Vec<User> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn combined_queries_can_be_ordered_by_multiple_columns() {
+    let connection = connection_with_users_and_posts();
+
+    let source = posts::table.select((posts::title, posts::user_id))
+        .union(users::table.select((users::name, users::id)))
+        .order((posts::title.desc(), posts::user_id));
+    let expected_data = vec![
+        ("World".to_string(), 2),
+        ("Tess".to_string(), 2),
+        ("Sean".to_string(), 1),
+        ("Hello".to_string(), 1),
+    ];
+    let data: Vec<(String, i32)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn combined_queries_are_ordered_by_position_when_selected_names_repeat() {
+    let connection = connection_with_users_and_posts();
+
+    let source = users::table.inner_join(posts::table)
+        .select((posts::id, users::id))
+        .union(posts::table.select((posts::id, posts::user_id)))
+        .order((users::id.desc(), posts::id));
+    let expected_data = vec![(3, 2), (1, 1), (2, 1)];
+    let data: Vec<(i32, i32)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+fn ordered_by_name<Q: OrderDsl<users::name>>(query: Q) -> helper_types::Order<Q, users::name> {
+    query.order(users::name)
+}
+
+#[test]
+fn combined_queries_can_be_ordered_through_order_dsl() {
+    let connection = connection_with_users_and_posts();
+
+    let source = ordered_by_name(users::table.select(users::name)
+        .intersect(posts::table.select(posts::title)));
+    let expected_data = vec!["Sean".to_string()];
+    let data: Vec<String> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
@@ -0,0 +1,18 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+
+table! {
+    employees {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+alias!(employees as managers);
+
+fn main() {
+    let source = managers.select(employees::name);
+    //~^ ERROR E0277
+}
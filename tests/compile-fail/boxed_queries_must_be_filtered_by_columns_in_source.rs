@@ -0,0 +1,26 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+
+table! {
+    users {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Serial,
+        title -> VarChar,
+    }
+}
+
+allow_tables_to_appear_in_same_query!(users, posts);
+
+fn main() {
+    let mut query = users::table.into_boxed();
+    query = query.filter(posts::title.eq("Hello"));
+    //~^ ERROR E0277
+}
@@ -0,0 +1,27 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+
+table! {
+    users {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Serial,
+        user_id -> Integer,
+    }
+}
+
+joinable!(posts -> users (user_id = id));
+allow_tables_to_appear_in_same_query!(users, posts);
+
+fn main() {
+    let source = users::table.inner_join(posts::table).inner_join(users::table)
+        .select(users::name);
+    //~^ ERROR E0599
+}
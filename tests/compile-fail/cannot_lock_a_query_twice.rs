@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+
+table! {
+    users {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+    let conn = Connection::establish("").unwrap();
+
+    let _ = users.select(name).for_update().for_share().load::<String>(&conn);
+    //~^ ERROR E0277
+}
@@ -0,0 +1,22 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+use yaqb::expression::dsl::count;
+
+table! {
+    users {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+    let conn = Connection::establish("").unwrap();
+
+    let _ = users.select(name).distinct().for_update().load::<String>(&conn);
+    //~^ ERROR E0277
+    let _ = users.select(count(id)).for_update().load::<i64>(&conn);
+    //~^ ERROR E0277
+}
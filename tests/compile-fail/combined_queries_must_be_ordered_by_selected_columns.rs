@@ -0,0 +1,40 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+
+table! {
+    users {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Serial,
+        title -> VarChar,
+    }
+}
+
+table! {
+    comments {
+        id -> Serial,
+        body -> VarChar,
+    }
+}
+
+fn main() {
+    let conn = Connection::establish("").unwrap();
+
+    let _ = users::table.select(users::name)
+        .union(posts::table.select(posts::title))
+        .order(comments::body)
+        //~^ ERROR E0277
+        .load::<String>(&conn);
+    let _ = users::table.select(users::name)
+        .union(posts::table.select(posts::title))
+        .order(users::id)
+        //~^ ERROR E0277
+        .load::<String>(&conn);
+}
@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+use yaqb::expression::dsl::default;
+
+table! {
+    users {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let source = users.filter(name.eq(default()));
+    //~^ ERROR E0277
+}
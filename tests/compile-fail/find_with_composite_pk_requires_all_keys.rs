@@ -0,0 +1,17 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+
+table! {
+    user_roles (user_id, role_id) {
+        user_id -> Integer,
+        role_id -> Integer,
+    }
+}
+
+fn main() {
+    let connection = Connection::establish("").unwrap();
+    let role: Option<(i32, i32)> = connection.find(user_roles::table, 1).unwrap();
+    //~^ ERROR E0308
+}
@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+use yaqb::query_builder::insert_into;
+
+table! {
+    users {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Serial,
+        title -> VarChar,
+    }
+}
+
+fn main() {
+    let query = users::table.select(users::name);
+    let command = insert_into(posts::table).columns(posts::id).select_from(query);
+    //~^ ERROR type mismatch
+}
@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+
+table! {
+    users {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Serial,
+        user_id -> Integer,
+    }
+}
+
+table! {
+    comments {
+        id -> Serial,
+        post_id -> Integer,
+    }
+}
+
+allow_tables_to_appear_in_same_query!(users, posts);
+allow_tables_to_appear_in_same_query!(users, comments);
+allow_tables_to_appear_in_same_query!(posts, comments);
+
+fn main() {
+    let conn = Connection::establish("").unwrap();
+
+    let _ = users::table.inner_join(posts::table.on(comments::id.eq(1)))
+        //~^ ERROR E0277
+        .load::<((i32, String), (i32, i32))>(&conn);
+}
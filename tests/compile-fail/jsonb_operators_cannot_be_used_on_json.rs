@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+use yaqb::expression::dsl::*;
+
+table! {
+    events {
+        id -> Serial,
+        payload -> Json,
+    }
+}
+
+fn main() {
+    use self::events::dsl::*;
+
+    let source = events.filter(payload.has_key("kind"));
+    //~^ ERROR E0599
+}
@@ -0,0 +1,19 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+use yaqb::expression::dsl::row;
+
+table! {
+    users {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let source = users.filter(row((id, name)).eq((1, "Sean")));
+    //~^ ERROR E0277
+}
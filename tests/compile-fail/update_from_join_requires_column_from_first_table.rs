@@ -0,0 +1,28 @@
+#[macro_use]
+extern crate yaqb;
+
+use yaqb::*;
+use yaqb::query_builder::update;
+
+table! {
+    users {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Serial,
+        user_id -> Integer,
+        title -> VarChar,
+    }
+}
+
+joinable!(posts -> users (user_id = id));
+allow_tables_to_appear_in_same_query!(users, posts);
+
+fn main() {
+    let command = update(users::table.inner_join(posts::table)).set(posts::title.eq("Hello"));
+    //~^ ERROR type mismatch
+}
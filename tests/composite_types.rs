@@ -0,0 +1,157 @@
+use schema::*;
+use yaqb::*;
+use yaqb::expression::dsl::*;
+use yaqb::types::{FromSql, Nullable, Record, Text, Integer};
+
+sql_composite! {
+    #[sql_name = "address"]
+    AddressType {
+        street -> Text,
+        city -> Nullable<Text>,
+    }
+}
+
+table! {
+    people {
+        id -> Serial,
+        name -> VarChar,
+        address -> ::composite_types::AddressType,
+        previous_address -> Nullable<::composite_types::AddressType>,
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Address {
+    street: String,
+    city: Option<String>,
+}
+
+queriable! {
+    Address {
+        street -> String,
+        city -> Option<String>,
+    }
+}
+
+fn connection_with_people() -> Connection {
+    let connection = connection();
+    connection.execute("CREATE TYPE address AS (street TEXT, city TEXT)").unwrap();
+    connection.execute("CREATE TABLE people (
+        id SERIAL PRIMARY KEY,
+        name VARCHAR NOT NULL,
+        address address NOT NULL,
+        previous_address address
+    )").unwrap();
+    connection.execute("INSERT INTO people (name, address, previous_address) VALUES
+        ('Sean', ROW('1 Main St', 'Springfield'), ROW('2 Elm St', NULL)),
+        ('Tess', ROW('3 Oak St', 'Shelbyville'), NULL)").unwrap();
+    connection
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Person {
+    name: String,
+    address: (String, Option<String>),
+}
+
+queriable! {
+    Person {
+        name -> String,
+        address -> (String, Option<String>),
+    }
+}
+
+#[test]
+fn composite_values_can_be_loaded_into_tuple_fields() {
+    use self::people::dsl::*;
+
+    let connection = connection_with_people();
+    let data: Vec<Person> = people.select((name, address))
+        .order(id).load(&connection).unwrap().collect();
+    let expected = vec![
+        Person {
+            name: "Sean".to_string(),
+            address: ("1 Main St".to_string(), Some("Springfield".to_string())),
+        },
+        Person {
+            name: "Tess".to_string(),
+            address: ("3 Oak St".to_string(), Some("Shelbyville".to_string())),
+        },
+    ];
+    assert_eq!(expected, data);
+}
+
+#[test]
+fn composite_values_can_be_loaded_into_structs() {
+    use self::people::dsl::*;
+
+    let connection = connection_with_people();
+    let data: Vec<Option<Address>> = people.select(previous_address)
+        .order(id).load(&connection).unwrap().collect();
+    let expected = vec![
+        Some(Address { street: "2 Elm St".to_string(), city: None }),
+        None,
+    ];
+    assert_eq!(expected, data);
+}
+
+#[test]
+fn composite_values_can_be_bound() {
+    use self::people::dsl::*;
+
+    let connection = connection_with_people();
+    let springfield = ("1 Main St", Some("Springfield"));
+    let data: Vec<String> = people.select(name).filter(address.eq(springfield))
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec!["Sean".to_string()], data);
+
+    let no_city = ("2 Elm St", None::<&str>);
+    let data: Vec<String> = people.select(name).filter(previous_address.eq(no_city))
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec!["Sean".to_string()], data);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct IdAndName {
+    id: i32,
+    name: String,
+}
+
+queriable! {
+    IdAndName {
+        id -> i32,
+        name -> String,
+    }
+}
+
+#[test]
+fn row_expressions_can_be_loaded_into_structs() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let data: Vec<IdAndName> = users.select(row((id, name)))
+        .order(id).load(&connection).unwrap().collect();
+    let expected = vec![
+        IdAndName { id: 1, name: "Sean".to_string() },
+        IdAndName { id: 2, name: "Tess".to_string() },
+    ];
+    assert_eq!(expected, data);
+}
+
+#[test]
+fn records_can_be_read_into_tuples() {
+    let bytes = [0, 0, 0, 2, 0, 0, 0, 23, 0, 0, 0, 4, 0, 0, 0, 1, 0, 0, 0, 25, 255, 255, 255, 255];
+    let result: (i32, Option<String>) = FromSql::<Record<(Integer, Nullable<Text>)>>::from_sql(Some(&bytes)).unwrap();
+    assert_eq!((1, None), result);
+}
+
+#[test]
+fn records_with_the_wrong_number_of_fields_are_an_error() {
+    let bytes = [0, 0, 0, 1, 0, 0, 0, 23, 0, 0, 0, 4, 0, 0, 0, 1];
+    let result: Result<(i32, String), _> = FromSql::<Record<(Integer, Text)>>::from_sql(Some(&bytes));
+    assert!(result.is_err());
+
+    let truncated = [0, 0, 0, 2, 0, 0, 0, 23, 0, 0, 0, 4, 0, 0];
+    let result: Result<(i32, String), _> = FromSql::<Record<(Integer, Text)>>::from_sql(Some(&truncated));
+    assert!(result.is_err());
+}
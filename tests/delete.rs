@@ -0,0 +1,44 @@
+use schema::*;
+use yaqb::*;
+use yaqb::query_builder::delete;
+
+#[test]
+fn delete_records() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let command = delete(users.filter(name.eq("Sean")));
+    assert_eq!(Some(1), connection.execute_returning_count(&command).ok());
+
+    let data: Vec<String> = users.select(name).load(&connection).unwrap().collect();
+    assert_eq!(vec!["Tess".to_string()], data);
+}
+
+#[test]
+fn delete_returning_struct() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let command = delete(users.filter(id.eq(2)));
+    let user: Option<User> = connection.query_one(command).unwrap();
+    assert_eq!(Some(User::new(2, "Tess")), user);
+}
+
+#[test]
+fn delete_filtered_by_joined_table() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    setup_posts_table(&connection);
+    connection.execute("INSERT INTO posts (user_id, title) VALUES
+        (1, 'Hello'),
+        (2, 'World')
+    ").unwrap();
+
+    let source = users::table.inner_join(posts::table).filter(posts::title.eq("World"));
+    let command = delete(source);
+    assert_eq!(Some(1), connection.execute_returning_count(&command).ok());
+
+    let data: Vec<String> = users::table.select(users::name).load(&connection).unwrap().collect();
+    assert_eq!(vec!["Sean".to_string()], data);
+}
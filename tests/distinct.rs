@@ -0,0 +1,91 @@
+use schema::*;
+use yaqb::*;
+
+#[test]
+fn simple_distinct() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    setup_users_table(&connection);
+    connection.execute("INSERT INTO users (name) VALUES ('Sean'), ('Sean'), ('Tess')")
+        .unwrap();
+
+    let source = users.select(name).distinct().order(name);
+    let expected_data = vec!["Sean".to_string(), "Tess".to_string()];
+    let data: Vec<String> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn distinct_on() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    setup_users_table(&connection);
+    connection.execute("INSERT INTO users (name, hair_color) VALUES
+        ('Sean', 'black'), ('Sean', NULL), ('Tess', 'brown'), ('Tess', NULL)
+    ").unwrap();
+
+    let source = users.select((name, hair_color)).order((name, hair_color)).distinct_on(name);
+    let expected_data = vec![
+        ("Sean".to_string(), Some("black".to_string())),
+        ("Tess".to_string(), Some("brown".to_string())),
+    ];
+    let data: Vec<_> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn distinct_on_latest_row_per_group() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    setup_users_table(&connection);
+    connection.execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Sean')")
+        .unwrap();
+
+    let source = users.select((name, id)).distinct_on(name).order((name, id.desc()));
+    let expected_data = vec![("Sean".to_string(), 3), ("Tess".to_string(), 2)];
+    let data: Vec<(String, i32)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn distinct_on_with_descending_order_and_tiebreaker() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    setup_users_table(&connection);
+    connection.execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Sean')")
+        .unwrap();
+
+    let source = users.select((name, id)).distinct_on(name).order((name.desc(), id));
+    let expected_data = vec![("Tess".to_string(), 2), ("Sean".to_string(), 1)];
+    let data: Vec<(String, i32)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn distinct_on_with_three_ordering_expressions() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    setup_users_table(&connection);
+    connection.execute("INSERT INTO users (name, hair_color) VALUES
+        ('Sean', 'black'), ('Sean', 'brown'), ('Tess', 'brown'), ('Tess', 'brown')
+    ").unwrap();
+
+    let source = users.select((name, hair_color, id)).distinct_on(name)
+        .order((name, hair_color.desc(), id.desc()));
+    let expected_data = vec![
+        ("Sean".to_string(), Some("brown".to_string()), 2),
+        ("Tess".to_string(), Some("brown".to_string()), 4),
+    ];
+    let data: Vec<(String, Option<String>, i32)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
@@ -0,0 +1,112 @@
+use schema::connection;
+use yaqb::*;
+use yaqb::types::FromSql;
+
+sql_enum! {
+    #[sql_name = "mood"]
+    MoodType -> Mood {
+        Sad = "sad",
+        Ok = "ok",
+        Happy = "happy",
+    }
+}
+
+table! {
+    moods {
+        id -> Serial,
+        name -> VarChar,
+        mood -> ::enums::MoodType,
+        previous_mood -> Nullable<::enums::MoodType>,
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+struct Person {
+    id: i32,
+    name: String,
+    mood: Mood,
+    previous_mood: Option<Mood>,
+}
+
+queriable! {
+    Person {
+        id -> i32,
+        name -> String,
+        mood -> Mood,
+        previous_mood -> Option<Mood>,
+    }
+}
+
+struct NewPerson<'a> {
+    name: &'a str,
+    mood: Mood,
+    previous_mood: Option<Mood>,
+}
+
+insertable! {
+    NewPerson<'a> => moods {
+        name -> &'a str,
+        mood -> Mood,
+        previous_mood -> Option<Mood>,
+    }
+}
+
+fn connection_with_moods() -> Connection {
+    let connection = connection();
+    connection.execute("CREATE TYPE mood AS ENUM ('sad', 'ok', 'happy')").unwrap();
+    connection.execute("CREATE TABLE moods (
+        id SERIAL PRIMARY KEY,
+        name VARCHAR NOT NULL,
+        mood mood NOT NULL,
+        previous_mood mood
+    )").unwrap();
+    connection
+}
+
+#[test]
+fn enums_can_be_inserted_and_loaded() {
+    let connection = connection_with_moods();
+    let new_people = vec![
+        NewPerson { name: "Sean", mood: Mood::Happy, previous_mood: Some(Mood::Sad) },
+        NewPerson { name: "Tess", mood: Mood::Ok, previous_mood: None },
+    ];
+    let inserted: Vec<Person> = connection.insert(&moods::table, &new_people)
+        .unwrap().collect();
+
+    let expected = vec![
+        Person { id: 1, name: "Sean".to_string(), mood: Mood::Happy, previous_mood: Some(Mood::Sad) },
+        Person { id: 2, name: "Tess".to_string(), mood: Mood::Ok, previous_mood: None },
+    ];
+    assert_eq!(expected, inserted);
+    let loaded: Vec<Person> = moods::table.load(&connection).unwrap().collect();
+    assert_eq!(expected, loaded);
+}
+
+#[test]
+fn enums_can_be_used_in_filters() {
+    use self::moods::dsl::*;
+
+    let connection = connection_with_moods();
+    connection.execute("INSERT INTO moods (name, mood, previous_mood) VALUES
+        ('Sean', 'happy', 'sad'), ('Tess', 'ok', NULL), ('Jim', 'sad', 'happy')").unwrap();
+
+    let happy: Vec<String> = moods.select(name).filter(mood.eq(Mood::Happy))
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec!["Sean".to_string()], happy);
+
+    let used_to_be_happy: Vec<String> = moods.select(name).filter(previous_mood.eq(Mood::Happy))
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec!["Jim".to_string()], used_to_be_happy);
+
+    let at_least_ok: Vec<String> = moods.select(name).filter(mood.ge(Mood::Ok))
+        .order(name).load(&connection).unwrap().collect();
+    assert_eq!(vec!["Sean".to_string(), "Tess".to_string()], at_least_ok);
+}
+
+#[test]
+fn unrecognized_labels_are_an_error() {
+    let result: Result<Mood, _> = FromSql::<MoodType>::from_sql(Some(b"angry"));
+    assert!(result.is_err());
+    let result: Result<Mood, _> = FromSql::<MoodType>::from_sql(None);
+    assert!(result.is_err());
+}
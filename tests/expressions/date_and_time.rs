@@ -54,7 +54,7 @@ fn date_uses_sql_function_date() {
                        ").unwrap();
 
     let expected_data = vec![1, 3];
-    let actual_data: Vec<_> = has_timestamps.select(id)
+    let actual_data: Vec<i32> = has_timestamps.select(id)
         .filter(date(created_at).eq(date(updated_at)))
         .load(&connection)
         .unwrap().collect();
@@ -0,0 +1,131 @@
+use schema::connection;
+use yaqb::*;
+use yaqb::expression::dsl::*;
+
+table! {
+    events {
+        id -> Serial,
+        payload -> Jsonb,
+        raw_payload -> Json,
+    }
+}
+
+fn connection_with_events() -> Connection {
+    let connection = connection();
+    connection.execute("CREATE TABLE events (
+        id SERIAL PRIMARY KEY,
+        payload JSONB NOT NULL,
+        raw_payload JSON NOT NULL
+    )").unwrap();
+    connection.execute(r#"INSERT INTO events (payload, raw_payload) VALUES
+        ('{"kind": "signup", "user": {"id": 1, "tags": ["new"]}}', '{"kind": "signup"}'),
+        ('{"kind": "login", "user": {"id": 2}}', '{"kind": "login"}'),
+        ('{"kind": "logout", "scores": [10, 20]}', '{"kind": "logout"}')
+    "#).unwrap();
+    connection
+}
+
+#[test]
+fn filter_by_text_retrieved_from_jsonb() {
+    use self::events::dsl::*;
+
+    let connection = connection_with_events();
+
+    let data: Vec<i32> = events.select(id).filter(payload.get_text("kind").eq("login"))
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec![2], data);
+}
+
+#[test]
+fn select_text_retrieved_from_json() {
+    use self::events::dsl::*;
+
+    let connection = connection_with_events();
+
+    let data: Vec<Option<String>> = events.select(raw_payload.get_text("kind")).order(id)
+        .load(&connection).unwrap().collect();
+    let expected_data = vec![
+        Some("signup".to_string()),
+        Some("login".to_string()),
+        Some("logout".to_string()),
+    ];
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn retrieving_nested_values() {
+    use self::events::dsl::*;
+
+    let connection = connection_with_events();
+
+    let data: Vec<Option<String>> = events.select(payload.get("user").get_text("id"))
+        .order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![Some("1".to_string()), Some("2".to_string()), None], data);
+
+    let data: Vec<Option<String>> = events.select(payload.get("scores").get_text_index(1))
+        .order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![None, None, Some("20".to_string())], data);
+
+    let path = vec!["user", "tags"];
+    let data: Vec<Option<String>> = events.select(payload.get_path(path).get_text_index(0))
+        .order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![Some("new".to_string()), None, None], data);
+}
+
+#[test]
+fn filter_by_jsonb_keys() {
+    use self::events::dsl::*;
+
+    let connection = connection_with_events();
+
+    let data: Vec<i32> = events.select(id).filter(payload.has_key("scores"))
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec![3], data);
+
+    let data: Vec<i32> = events.select(id).filter(payload.has_any_key(vec!["scores", "user"]))
+        .order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![1, 2, 3], data);
+
+    let data: Vec<i32> = events.select(id).filter(payload.has_all_keys(vec!["kind", "user"]))
+        .order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![1, 2], data);
+}
+
+#[cfg(feature = "serde_json")]
+mod with_serde_json {
+    extern crate serde_json;
+
+    use self::serde_json::Value;
+    use super::connection_with_events;
+    use super::events::dsl::*;
+    use yaqb::*;
+    use yaqb::expression::dsl::*;
+
+    #[test]
+    fn loading_json_values() {
+        let connection = connection_with_events();
+
+        let data: Vec<(Value, Value)> = events.select((payload, raw_payload)).order(id)
+            .limit(1).load(&connection).unwrap().collect();
+        let expected_payload = serde_json::from_str(
+            r#"{"kind": "signup", "user": {"id": 1, "tags": ["new"]}}"#).unwrap();
+        let expected_raw_payload = serde_json::from_str(r#"{"kind": "signup"}"#).unwrap();
+        assert_eq!(vec![(expected_payload, expected_raw_payload)], data);
+    }
+
+    #[test]
+    fn filter_by_jsonb_containment() {
+        let connection = connection_with_events();
+
+        let user: Value = serde_json::from_str(r#"{"user": {"id": 2}}"#).unwrap();
+        let data: Vec<i32> = events.select(id).filter(payload.contains(user))
+            .load(&connection).unwrap().collect();
+        assert_eq!(vec![2], data);
+
+        let document: Value = serde_json::from_str(
+            r#"{"kind": "logout", "scores": [10, 20], "extra": true}"#).unwrap();
+        let data: Vec<i32> = events.select(id).filter(payload.is_contained_by(document))
+            .load(&connection).unwrap().collect();
+        assert_eq!(vec![3], data);
+    }
+}
@@ -1,5 +1,7 @@
 mod date_and_time;
+mod json;
 mod ops;
+mod ranges;
 
 use schema::{connection, NewUser, setup_users_table};
 use schema::users::dsl::*;
@@ -8,12 +8,12 @@ fn adding_literal_to_column() {
     let connection = connection_with_sean_and_tess_in_users_table();
 
     let expected_data = vec![2, 3];
-    let data: Vec<_> = users.select(id + 1).load(&connection)
+    let data: Vec<i32> = users.select(id + 1).load(&connection)
         .unwrap().collect();
     assert_eq!(expected_data, data);
 
     let expected_data = vec![3, 4];
-    let data: Vec<_> = users.select(id + 2).load(&connection)
+    let data: Vec<i32> = users.select(id + 2).load(&connection)
         .unwrap().collect();
     assert_eq!(expected_data, data);
 }
@@ -25,7 +25,7 @@ fn adding_column_to_column() {
     let connection = connection_with_sean_and_tess_in_users_table();
 
     let expected_data = vec![2, 4];
-    let data: Vec<_> = users.select(id + id).load(&connection)
+    let data: Vec<i32> = users.select(id + id).load(&connection)
         .unwrap().collect();
     assert_eq!(expected_data, data);
 }
@@ -37,7 +37,7 @@ fn adding_multiple_times() {
     let connection = connection_with_sean_and_tess_in_users_table();
 
     let expected_data = vec![4, 5];
-    let data: Vec<_> = users.select(id + 1 + 2).load(&connection)
+    let data: Vec<i32> = users.select(id + 1 + 2).load(&connection)
         .unwrap().collect();
     assert_eq!(expected_data, data);
 }
@@ -49,7 +49,7 @@ fn subtracting_literal_from_column() {
     let connection = connection_with_sean_and_tess_in_users_table();
 
     let expected_data = vec![0, 1];
-    let data: Vec<_> = users.select(id - 1).load(&connection)
+    let data: Vec<i32> = users.select(id - 1).load(&connection)
         .unwrap().collect();
     assert_eq!(expected_data, data);
 }
@@ -61,7 +61,7 @@ fn adding_then_subtracting() {
     let connection = connection_with_sean_and_tess_in_users_table();
 
     let expected_data = vec![2, 3];
-    let data: Vec<_> = users.select(id + 2 - 1).load(&connection)
+    let data: Vec<i32> = users.select(id + 2 - 1).load(&connection)
         .unwrap().collect();
     assert_eq!(expected_data, data);
 }
@@ -73,7 +73,7 @@ fn multiplying_column() {
     let connection = connection_with_sean_and_tess_in_users_table();
 
     let expected_data = vec![3, 6];
-    let data: Vec<_> = users.select(id * 3).load(&connection)
+    let data: Vec<i32> = users.select(id * 3).load(&connection)
         .unwrap().collect();
     assert_eq!(expected_data, data);
 }
@@ -85,7 +85,7 @@ fn dividing_column() {
     let connection = connection_with_sean_and_tess_in_users_table();
 
     let expected_data = vec![0, 1];
-    let data: Vec<_> = users.select(id / 2).load(&connection)
+    let data: Vec<i32> = users.select(id / 2).load(&connection)
         .unwrap().collect();
     assert_eq!(expected_data, data);
 }
@@ -99,7 +99,35 @@ fn mix_and_match_all_numeric_ops() {
     connection.insert_returning_count(&users, &data).unwrap();
 
     let expected_data = vec![4, 6, 7, 9];
-    let data: Vec<_> = users.select(id * 3 / 2 + 4 - 1).load(&connection)
+    let data: Vec<i32> = users.select(id * 3 / 2 + 4 - 1).load(&connection)
         .unwrap().collect();
     assert_eq!(expected_data, data);
 }
+
+table! {
+    prices {
+        id -> Serial,
+        amount -> Numeric,
+    }
+}
+
+numeric_expr!(prices::amount);
+
+#[test]
+fn numeric_columns_support_arithmetic() {
+    use self::prices::dsl::*;
+    use yaqb::types::structs::PgNumeric;
+
+    let connection = connection();
+    connection.execute("CREATE TABLE prices (id SERIAL PRIMARY KEY, amount NUMERIC(12, 2) NOT NULL)")
+        .unwrap();
+    connection.execute("INSERT INTO prices (amount) VALUES (1.25), (10.50)").unwrap();
+
+    let expected_data = vec![
+        PgNumeric::Positive { weight: 0, scale: 2, digits: vec![2, 5000] },
+        PgNumeric::Positive { weight: 0, scale: 2, digits: vec![21] },
+    ];
+    let data: Vec<PgNumeric> = prices.select(amount + amount).order(id)
+        .load(&connection).unwrap().collect();
+    assert_eq!(expected_data, data);
+}
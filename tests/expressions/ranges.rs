@@ -0,0 +1,140 @@
+use schema::connection;
+use yaqb::*;
+use yaqb::expression::dsl::*;
+use yaqb::types::structs::{PgRange, PgTimestamp, RangeBound};
+
+table! {
+    bookings {
+        id -> Serial,
+        during -> Range<Timestamptz>,
+        guests -> Nullable<Range<Integer>>,
+    }
+}
+
+struct NewBooking {
+    during: PgRange<PgTimestamp>,
+    guests: Option<PgRange<i32>>,
+}
+
+insertable! {
+    NewBooking => bookings {
+        during -> PgRange<PgTimestamp>,
+        guests -> Option<PgRange<i32>>,
+    }
+}
+
+const HOUR: i64 = 3_600_000_000;
+
+fn hours(start: i64, end: i64) -> PgRange<PgTimestamp> {
+    PgRange::from(PgTimestamp(start * HOUR)..PgTimestamp(end * HOUR))
+}
+
+fn connection_with_bookings() -> Connection {
+    let connection = connection();
+    connection.execute("SET TIME ZONE 'UTC'").unwrap();
+    connection.execute("CREATE TABLE bookings (
+        id SERIAL PRIMARY KEY,
+        during TSTZRANGE NOT NULL,
+        guests INT4RANGE,
+        EXCLUDE USING gist (during WITH &&)
+    )").unwrap();
+    connection.execute("INSERT INTO bookings (during, guests) VALUES
+        ('[2000-01-01 01:00+00, 2000-01-01 03:00+00)', '[1, 2]'),
+        ('[2000-01-01 03:00+00, 2000-01-01 05:00+00)', '[2,)'),
+        ('[2000-01-01 08:00+00, 2000-01-01 09:00+00)', NULL)
+    ").unwrap();
+    connection
+}
+
+#[test]
+fn ranges_can_be_loaded() {
+    use self::bookings::dsl::*;
+
+    let connection = connection_with_bookings();
+    let data: Vec<(PgRange<PgTimestamp>, Option<PgRange<i32>>)> = bookings.select((during, guests))
+        .order(id).load(&connection).unwrap().collect();
+    let expected_data = vec![
+        (hours(1, 3), Some(PgRange::from(1..3))),
+        (hours(3, 5), Some(PgRange::new(RangeBound::Inclusive(2), RangeBound::Unbounded))),
+        (hours(8, 9), None),
+    ];
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn ranges_can_be_inserted() {
+    use self::bookings::dsl::*;
+
+    let connection = connection_with_bookings();
+    let new_booking = NewBooking {
+        during: PgRange::new(
+            RangeBound::Exclusive(PgTimestamp(5 * HOUR)),
+            RangeBound::Inclusive(PgTimestamp(6 * HOUR)),
+        ),
+        guests: Some(PgRange::Empty),
+    };
+    connection.insert_returning_count(&bookings, &new_booking).unwrap();
+
+    let data: Vec<(PgRange<PgTimestamp>, Option<PgRange<i32>>)> = bookings.select((during, guests))
+        .filter(id.eq(4)).load(&connection).unwrap().collect();
+    assert_eq!(vec![(new_booking.during, new_booking.guests)], data);
+
+    let overlapping_booking = NewBooking { during: hours(4, 6), guests: None };
+    assert!(connection.insert_returning_count(&bookings, &overlapping_booking).is_err());
+}
+
+#[test]
+fn filter_by_ranges_containing_an_element() {
+    use self::bookings::dsl::*;
+
+    let connection = connection_with_bookings();
+    let data: Vec<i32> = bookings.select(id).filter(during.contains(PgTimestamp(3 * HOUR)))
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec![2], data);
+
+    let data: Vec<i32> = bookings.select(id).filter(guests.contains(2))
+        .order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![1, 2], data);
+}
+
+#[test]
+fn filter_by_ranges_containing_or_contained_by_a_range() {
+    use self::bookings::dsl::*;
+
+    let connection = connection_with_bookings();
+    let data: Vec<i32> = bookings.select(id).filter(during.contains_range(hours(1, 2)))
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec![1], data);
+
+    let data: Vec<i32> = bookings.select(id).filter(during.is_contained_by(hours(0, 6)))
+        .order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![1, 2], data);
+}
+
+#[test]
+fn filter_by_overlapping_and_adjacent_ranges() {
+    use self::bookings::dsl::*;
+
+    let connection = connection_with_bookings();
+    let data: Vec<i32> = bookings.select(id).filter(during.overlaps_with(hours(2, 4)))
+        .order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![1, 2], data);
+
+    let data: Vec<i32> = bookings.select(id).filter(during.is_adjacent_to(hours(5, 8)))
+        .order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![2, 3], data);
+}
+
+#[test]
+fn select_bounds_of_ranges() {
+    use self::bookings::dsl::*;
+
+    let connection = connection_with_bookings();
+    let data: Vec<(Option<i32>, Option<i32>)> = bookings.select((guests.lower(), guests.upper()))
+        .order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![(Some(1), Some(3)), (Some(2), None), (None, None)], data);
+
+    let data: Vec<Option<PgTimestamp>> = bookings.select(during.upper())
+        .filter(during.lower().eq(PgTimestamp(HOUR))).load(&connection).unwrap().collect();
+    assert_eq!(vec![Some(PgTimestamp(3 * HOUR))], data);
+}
@@ -0,0 +1,105 @@
+use schema::connection;
+use std::error::Error;
+use std::io::Write;
+use yaqb::*;
+use yaqb::expression::AsExpression;
+use yaqb::expression::bound::Bound;
+use yaqb::types::{NativeSqlType, FromSql, ToSql, IsNull, Text, TypeName};
+
+#[derive(Clone, Copy)]
+pub struct Citext;
+
+impl NativeSqlType for Citext {
+    fn oid() -> u32 {
+        0
+    }
+
+    fn type_name() -> Option<TypeName> {
+        Some(TypeName::new("citext"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Handle(pub String);
+
+impl FromSql<Citext> for Handle {
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error>> {
+        FromSql::<Text>::from_sql(bytes).map(Handle)
+    }
+}
+
+impl ToSql<Citext> for Handle {
+    fn to_sql<W: Write>(&self, out: &mut W) -> Result<IsNull, Box<Error>> {
+        ToSql::<Text>::to_sql(&self.0, out)
+    }
+}
+
+impl Queriable<Citext> for Handle {
+    type Row = Self;
+
+    fn build(row: Self) -> Self {
+        row
+    }
+}
+
+impl AsExpression<Citext> for Handle {
+    type Expression = Bound<Citext, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+table! {
+    handles {
+        id -> Serial,
+        handle -> ::extension_types::Citext,
+    }
+}
+
+fn connection_with_citext() -> Connection {
+    let connection = connection();
+    connection.execute("CREATE EXTENSION IF NOT EXISTS citext").unwrap();
+    connection
+}
+
+#[test]
+fn values_of_extension_types_are_bound_with_their_oid() {
+    use self::handles::dsl::*;
+
+    let connection = connection_with_citext();
+    connection.execute("CREATE TABLE handles (id SERIAL PRIMARY KEY, handle CITEXT NOT NULL)")
+        .unwrap();
+    connection.execute("INSERT INTO handles (handle) VALUES ('Sean'), ('Tess')").unwrap();
+
+    // Comparing against a text value would be case sensitive
+    let found: Vec<Handle> = handles.select(handle).filter(handle.eq(Handle("SEAN".into())))
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec![Handle("Sean".into())], found);
+}
+
+#[test]
+fn type_oids_are_looked_up_by_name() {
+    let connection = connection_with_citext();
+    let expected: Vec<(i32, i32)> = connection.query_sql::<(types::Integer, types::Integer), _>(
+        "SELECT oid::int4, typarray::int4 FROM pg_type WHERE typname = 'citext'")
+        .unwrap().collect();
+    let (expected_oid, expected_array_oid) = expected[0];
+
+    let citext = TypeName::new("citext");
+    assert_eq!(Ok(expected_oid as u32), connection.type_oid(citext));
+    assert_eq!(Ok(expected_array_oid as u32), connection.type_oid(citext.array()));
+    assert_eq!(Ok(expected_oid as u32), connection.type_oid(citext));
+}
+
+#[test]
+fn binding_a_type_which_does_not_exist_is_an_error() {
+    use self::handles::dsl::*;
+
+    let connection = connection();
+    connection.execute("CREATE TABLE handles (id SERIAL PRIMARY KEY, handle TEXT NOT NULL)")
+        .unwrap();
+    let query = handles.select(handle).filter(handle.eq(Handle("Sean".into())));
+    assert!(connection.query_all::<_, Handle>(query).is_err());
+    assert!(connection.type_oid(TypeName::new("no_such_type")).is_err());
+}
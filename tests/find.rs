@@ -35,3 +35,62 @@ fn find_with_non_serial_pk() {
     assert_eq!(Ok(Some("Tess".to_string())), connection.find(users, "Tess".to_string()));
     assert_eq!(Ok(None::<String>), connection.find(users, "Wibble"));
 }
+
+table! {
+    user_roles (user_id, role_id) {
+        user_id -> Integer,
+        role_id -> Integer,
+        granted_by -> VarChar,
+    }
+}
+
+#[test]
+fn find_with_composite_pk() {
+    use self::user_roles::table as user_roles;
+
+    let connection = connection();
+    connection.execute("CREATE TABLE user_roles (
+        user_id INTEGER NOT NULL,
+        role_id INTEGER NOT NULL,
+        granted_by VARCHAR NOT NULL,
+        PRIMARY KEY (user_id, role_id)
+    )").unwrap();
+    connection.execute("INSERT INTO user_roles (user_id, role_id, granted_by) VALUES
+        (1, 1, 'Sean'),
+        (1, 2, 'Tess'),
+        (2, 1, 'Jim')
+    ").unwrap();
+
+    assert_eq!(Ok(Some((1, 2, "Tess".to_string()))), connection.find(user_roles, (1, 2)));
+    assert_eq!(Ok(Some((2, 1, "Jim".to_string()))), connection.find(user_roles, (2, 1)));
+    assert_eq!(Ok(None::<(i32, i32, String)>), connection.find(user_roles, (2, 2)));
+}
+
+table! {
+    documents {
+        id -> Uuid,
+        title -> VarChar,
+    }
+}
+
+#[test]
+fn find_with_uuid_pk() {
+    use self::documents::table as documents;
+    use yaqb::types::structs::PgUuid;
+
+    let connection = connection();
+    connection.execute("CREATE TABLE documents (id UUID PRIMARY KEY, title VARCHAR NOT NULL)")
+        .unwrap();
+    connection.execute("INSERT INTO documents (id, title) VALUES
+        ('00000000-0000-0000-0000-000000000001', 'Hello'),
+        ('00000000-0000-0000-0000-000000000002', 'World')
+    ").unwrap();
+
+    let mut first_id = [0; 16];
+    first_id[15] = 1;
+    let first_id = PgUuid(first_id);
+    let missing_id = PgUuid([0xff; 16]);
+
+    assert_eq!(Ok(Some((first_id, "Hello".to_string()))), connection.find(documents, first_id));
+    assert_eq!(Ok(None::<(PgUuid, String)>), connection.find(documents, missing_id));
+}
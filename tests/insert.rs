@@ -133,3 +133,59 @@ fn insert_borrowed_content() {
     assert_eq!(expected_users, actual_users);
     assert_eq!(expected_users, inserted_users);
 }
+
+table! {
+    archived_posts {
+        id -> Serial,
+        user_id -> Integer,
+        title -> VarChar,
+    }
+}
+
+fn connection_with_posts_and_archive() -> Connection {
+    let connection = connection();
+    setup_posts_table(&connection);
+    connection.execute("CREATE TABLE archived_posts (
+        id SERIAL PRIMARY KEY,
+        user_id INTEGER NOT NULL,
+        title VARCHAR NOT NULL
+    )").unwrap();
+    connection.execute("INSERT INTO posts (user_id, title) VALUES
+        (1, 'Hello'),
+        (1, 'World'),
+        (2, 'Goodbye')
+    ").unwrap();
+    connection
+}
+
+#[test]
+fn insert_from_select_returning_count() {
+    use yaqb::query_builder::insert_into;
+
+    let connection = connection_with_posts_and_archive();
+
+    let query = posts::table.filter(posts::user_id.eq(1))
+        .select((posts::id, posts::user_id, posts::title));
+    let command = insert_into(archived_posts::table).select_from(query);
+    assert_eq!(Some(2), connection.execute_returning_count(&command).ok());
+
+    let expected_data = vec![(1, 1, "Hello".to_string()), (2, 1, "World".to_string())];
+    let data: Vec<(i32, i32, String)> = archived_posts::table.order(archived_posts::id)
+        .load(&connection).unwrap().collect();
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn insert_from_select_into_some_columns_returning_rows() {
+    use yaqb::query_builder::insert_into;
+
+    let connection = connection_with_posts_and_archive();
+
+    let query = posts::table.filter(posts::user_id.eq(2))
+        .select((posts::user_id, posts::title));
+    let command = insert_into(archived_posts::table)
+        .columns((archived_posts::user_id, archived_posts::title))
+        .select_from(query);
+    let data: Vec<(i32, i32, String)> = connection.query_all(command).unwrap().collect();
+    assert_eq!(vec![(1, 2, "Goodbye".to_string())], data);
+}
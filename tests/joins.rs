@@ -229,3 +229,266 @@ fn select_right_side_with_nullable_column_first() {
 
     assert_eq!(expected_data, actual_data);
 }
+
+table! {
+    comments {
+        id -> Serial,
+        post_id -> Integer,
+        body -> VarChar,
+    }
+}
+
+joinable!(comments -> posts (post_id = id));
+allow_tables_to_appear_in_same_query!(comments, users);
+allow_tables_to_appear_in_same_query!(comments, posts);
+
+fn connection_with_comments() -> Connection {
+    let connection = connection();
+    setup_users_table(&connection);
+    setup_posts_table(&connection);
+    connection.execute("CREATE TABLE comments (
+        id SERIAL PRIMARY KEY,
+        post_id INTEGER NOT NULL,
+        body VARCHAR NOT NULL
+    )").unwrap();
+
+    connection.execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess')")
+        .unwrap();
+    connection.execute("INSERT INTO posts (user_id, title) VALUES
+        (1, 'Hello'),
+        (1, 'World')
+    ").unwrap();
+    connection.execute("INSERT INTO comments (post_id, body) VALUES
+        (1, 'First'),
+        (1, 'Second')
+    ").unwrap();
+    connection
+}
+
+#[test]
+fn inner_joins_across_three_tables() {
+    let connection = connection_with_comments();
+
+    let source = users::table.inner_join(posts::table).inner_join(comments::table)
+        .select((users::name, posts::title, comments::body))
+        .order(comments::id);
+    let expected_data = vec![
+        ("Sean".to_string(), "Hello".to_string(), "First".to_string()),
+        ("Sean".to_string(), "Hello".to_string(), "Second".to_string()),
+    ];
+    let actual_data: Vec<(String, String, String)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn loading_all_columns_across_three_tables_nests_results() {
+    let connection = connection_with_comments();
+
+    let source = users::table.inner_join(posts::table).inner_join(comments::table)
+        .filter(comments::id.eq(1));
+    let expected_data = vec![
+        ((User::new(1, "Sean"), Post::new(1, 1, "Hello", None)), (1, 1, "First".to_string())),
+    ];
+    let actual_data: Vec<((User, Post), (i32, i32, String))> = source.load(&connection)
+        .unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn left_outer_joins_across_three_tables() {
+    let connection = connection_with_comments();
+
+    let source = users::table.left_outer_join(posts::table).left_outer_join(comments::table)
+        .select((users::name, posts::title, comments::body));
+    let expected_data = vec![
+        ("Sean".to_string(), Some("Hello".to_string()), Some("First".to_string())),
+        ("Sean".to_string(), Some("Hello".to_string()), Some("Second".to_string())),
+        ("Sean".to_string(), Some("World".to_string()), None),
+        ("Tess".to_string(), None, None),
+    ];
+    let mut actual_data: Vec<(String, Option<String>, Option<String>)> = source.load(&connection)
+        .unwrap().collect();
+    actual_data.sort();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn join_can_find_predicate_through_earlier_table() {
+    let connection = connection_with_comments();
+
+    let source = posts::table.inner_join(comments::table).inner_join(users::table)
+        .select((users::name, comments::body))
+        .order(comments::id);
+    let expected_data = vec![
+        ("Sean".to_string(), "First".to_string()),
+        ("Sean".to_string(), "Second".to_string()),
+    ];
+    let actual_data: Vec<(String, String)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn inner_join_with_explicit_condition() {
+    let connection = connection_with_comments();
+
+    let source = users::table.inner_join(comments::table.on(comments::id.eq(users::id)))
+        .select((users::name, comments::body))
+        .order(users::id);
+    let expected_data = vec![
+        ("Sean".to_string(), "First".to_string()),
+        ("Tess".to_string(), "Second".to_string()),
+    ];
+    let actual_data: Vec<(String, String)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn explicit_condition_replaces_joinable_predicate() {
+    let connection = connection_with_comments();
+
+    let source = users::table.left_outer_join(posts::table.on(posts::id.eq(users::id)))
+        .select((users::name, posts::title))
+        .order(users::id);
+    let expected_data = vec![
+        ("Sean".to_string(), Some("Hello".to_string())),
+        ("Tess".to_string(), Some("World".to_string())),
+    ];
+    let actual_data: Vec<(String, Option<String>)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn right_outer_joins() {
+    let connection = connection_with_comments();
+
+    let source = posts::table.right_outer_join(users::table)
+        .select((posts::title, users::name))
+        .order(users::id);
+    let expected_data = vec![
+        (Some("Hello".to_string()), "Sean".to_string()),
+        (Some("World".to_string()), "Sean".to_string()),
+        (None, "Tess".to_string()),
+    ];
+    let mut actual_data: Vec<(Option<String>, String)> = source.load(&connection).unwrap().collect();
+    actual_data.sort_by(|a, b| (&a.1, &a.0).cmp(&(&b.1, &b.0)));
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn right_outer_join_makes_left_side_nullable() {
+    let connection = connection_with_comments();
+
+    let source = posts::table.right_outer_join(users::table)
+        .filter(users::name.eq("Tess"));
+    let expected_data = vec![(None, User::new(2, "Tess"))];
+    let actual_data: Vec<(Option<Post>, User)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn full_outer_joins() {
+    let connection = connection_with_comments();
+    connection.execute("INSERT INTO posts (user_id, title) VALUES (3, 'Orphaned')").unwrap();
+
+    let source = users::table.full_outer_join(posts::table)
+        .select((users::name, posts::title));
+    let expected_data = vec![
+        (None, Some("Orphaned".to_string())),
+        (Some("Sean".to_string()), Some("Hello".to_string())),
+        (Some("Sean".to_string()), Some("World".to_string())),
+        (Some("Tess".to_string()), None),
+    ];
+    let mut actual_data: Vec<(Option<String>, Option<String>)> = source.load(&connection)
+        .unwrap().collect();
+    actual_data.sort();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn outer_joins_across_three_tables_skip_every_column_of_null_rows() {
+    let connection = connection_with_comments();
+    connection.execute("INSERT INTO comments (post_id, body) VALUES (3, 'Orphaned')").unwrap();
+
+    let source = users::table.inner_join(posts::table).full_outer_join(comments::table);
+    let expected_data = vec![(None, Some((3, 3, "Orphaned".to_string())))];
+    let mut actual_data: Vec<(Option<(User, Post)>, Option<(i32, i32, String)>)> =
+        source.load(&connection).unwrap().collect();
+    actual_data.retain(|row| row.0.is_none());
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[test]
+fn cross_joins() {
+    let connection = connection_with_comments();
+
+    let source = users::table.cross_join(comments::table)
+        .select((users::name, comments::body));
+    let expected_data = vec![
+        ("Sean".to_string(), "First".to_string()),
+        ("Sean".to_string(), "Second".to_string()),
+        ("Tess".to_string(), "First".to_string()),
+        ("Tess".to_string(), "Second".to_string()),
+    ];
+    let mut actual_data: Vec<(String, String)> = source.load(&connection).unwrap().collect();
+    actual_data.sort();
+
+    assert_eq!(expected_data, actual_data);
+}
+
+#[allow(deprecated)]
+mod select_column_workaround_still_allows_selecting_from_joins {
+    use schema::{connection, setup_users_table, setup_posts_table};
+    use yaqb::*;
+
+    table! {
+        users {
+            id -> Serial,
+            name -> VarChar,
+        }
+    }
+
+    table! {
+        posts {
+            id -> Serial,
+            user_id -> Integer,
+            title -> VarChar,
+        }
+    }
+
+    joinable!(posts -> users (user_id = id));
+    select_column_workaround!(users -> posts (id, name));
+    select_column_workaround!(posts -> users (id, user_id, title));
+
+    #[test]
+    fn select_from_join_through_select_column_workaround() {
+        let connection = connection();
+        setup_users_table(&connection);
+        setup_posts_table(&connection);
+        connection.execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess')").unwrap();
+        connection.execute("INSERT INTO posts (user_id, title) VALUES
+            (1, 'Hello'),
+            (2, 'World')
+        ").unwrap();
+
+        let source = users::table.inner_join(posts::table)
+            .select((users::name, posts::title))
+            .order(users::id);
+        let expected_data = vec![
+            ("Sean".to_string(), "Hello".to_string()),
+            ("Tess".to_string(), "World".to_string()),
+        ];
+        let actual_data: Vec<(String, String)> = source.load(&connection).unwrap().collect();
+
+        assert_eq!(expected_data, actual_data);
+    }
+}
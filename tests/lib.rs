@@ -1,7 +1,15 @@
 extern crate quickcheck;
 #[macro_use] extern crate yaqb;
 
+mod alias;
 mod associations;
+mod boxed_queries;
+mod combine;
+mod delete;
+mod composite_types;
+mod distinct;
+mod enums;
+mod extension_types;
 mod expressions;
 mod filter;
 mod filter_operators;
@@ -9,12 +17,15 @@ mod find;
 mod insert;
 mod internal_details;
 mod joins;
+mod locking;
 mod macros;
 mod order;
 mod perf_details;
 mod schema;
+mod schema_qualified_tables;
 mod select;
 mod transactions;
 mod types;
 mod types_roundtrip;
 mod update;
+mod with_queries;
@@ -0,0 +1,74 @@
+use schema::*;
+use yaqb::*;
+
+#[test]
+fn select_for_update() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let expected_data = vec!["Sean".to_string()];
+    let data: Vec<String> = users.select(name).filter(id.eq(1)).for_update()
+        .load(&connection).unwrap().collect();
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn all_lock_modes_can_be_loaded() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let source = users.select(name).order(id);
+    let expected_data = vec!["Sean".to_string(), "Tess".to_string()];
+
+    let data: Vec<String> = source.for_update().load(&connection).unwrap().collect();
+    assert_eq!(expected_data, data);
+    let data: Vec<String> = source.for_no_key_update().load(&connection).unwrap().collect();
+    assert_eq!(expected_data, data);
+    let data: Vec<String> = source.for_share().load(&connection).unwrap().collect();
+    assert_eq!(expected_data, data);
+    let data: Vec<String> = source.for_key_share().no_wait().load(&connection).unwrap().collect();
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn raw_sql_selections_can_be_locked() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let data: Vec<String> = users.select_sql::<types::VarChar>("name").filter(id.eq(2))
+        .for_update().load(&connection).unwrap().collect();
+    assert_eq!(vec!["Tess".to_string()], data);
+}
+
+table! {
+    jobs {
+        id -> Serial,
+        name -> VarChar,
+    }
+}
+
+#[test]
+fn skip_locked_skips_rows_locked_by_another_connection() {
+    use self::jobs::dsl::*;
+
+    let conn1 = connection_without_transaction();
+    let conn2 = connection_without_transaction();
+    conn1.execute("DROP TABLE IF EXISTS jobs").unwrap();
+    conn1.execute("CREATE TABLE jobs (id SERIAL PRIMARY KEY, name VARCHAR NOT NULL)").unwrap();
+    conn1.execute("INSERT INTO jobs (name) VALUES ('first'), ('second')").unwrap();
+
+    conn1.begin_test_transaction().unwrap();
+    conn2.begin_test_transaction().unwrap();
+
+    let next_job = jobs.select(name).order(id).limit(1).for_update().skip_locked();
+    let first: Option<String> = conn1.query_one(next_job).unwrap();
+    let second: Option<String> = conn2.query_one(next_job).unwrap();
+
+    assert_eq!(Some("first".to_string()), first);
+    assert_eq!(Some("second".to_string()), second);
+
+    drop(conn1);
+    drop(conn2);
+    connection_without_transaction().execute("DROP TABLE jobs").unwrap();
+}
@@ -19,3 +19,61 @@ fn test_sql_function() {
     assert_eq!(vec![tess], users.filter(my_lower(name).eq("tess"))
         .load(&connection).unwrap().collect::<Vec<_>>());
 }
+
+table! {
+    #[sql_name = "LegacyItems"]
+    legacy_items {
+        id -> Serial,
+        #[sql_name = "type"]
+        item_type -> VarChar,
+        #[sql_name = "createdAt"]
+        created_at -> Integer,
+    }
+}
+
+pub struct NewLegacyItem {
+    item_type: String,
+    created_at: i32,
+}
+
+insertable! {
+    NewLegacyItem => legacy_items {
+        item_type -> String,
+        created_at -> i32,
+    }
+}
+
+changeset! {
+    NewLegacyItem => legacy_items {
+        item_type -> String,
+        created_at -> i32,
+    }
+}
+
+#[test]
+fn table_and_columns_can_have_sql_names_different_from_rust_names() {
+    use yaqb::query_builder::update;
+
+    let connection = connection();
+    connection.execute("CREATE TABLE \"LegacyItems\" (
+        id SERIAL PRIMARY KEY,
+        \"type\" VARCHAR NOT NULL,
+        \"createdAt\" INTEGER NOT NULL
+    )").unwrap();
+    let new_items: &[_] = &[
+        NewLegacyItem { item_type: "widget".to_string(), created_at: 1 },
+        NewLegacyItem { item_type: "gadget".to_string(), created_at: 2 },
+    ];
+    let inserted: Vec<(i32, String, i32)> = connection.insert(&legacy_items::table, new_items)
+        .unwrap().collect();
+    assert_eq!(vec![(1, "widget".to_string(), 1), (2, "gadget".to_string(), 2)], inserted);
+
+    let changes = NewLegacyItem { item_type: "gizmo".to_string(), created_at: 3 };
+    let command = update(legacy_items::table.filter(legacy_items::id.eq(2))).set(&changes);
+    connection.execute_returning_count(&command).unwrap();
+
+    let source = legacy_items::table.select(legacy_items::item_type)
+        .filter(legacy_items::created_at.gt(1));
+    let data: Vec<String> = source.load(&connection).unwrap().collect();
+    assert_eq!(vec!["gizmo".to_string()], data);
+}
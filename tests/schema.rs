@@ -81,8 +81,7 @@ queriable! {
     }
 }
 
-select_column_workaround!(users -> posts (id, name, hair_color));
-select_column_workaround!(posts -> users (id, user_id, title, body));
+allow_tables_to_appear_in_same_query!(users, posts);
 
 one_to_many!(users (User) -> posts (Post) on (user_id = id));
 
@@ -0,0 +1,104 @@
+use schema::connection;
+use yaqb::*;
+use yaqb::query_builder::update;
+
+table! {
+    billing.invoices {
+        id -> Serial,
+        customer -> VarChar,
+        amount -> Integer,
+    }
+}
+
+table! {
+    audit.events (event_id) {
+        event_id -> Serial,
+        invoice_id -> Integer,
+        description -> VarChar,
+    }
+}
+
+joinable!(events -> invoices (invoice_id = id));
+allow_tables_to_appear_in_same_query!(invoices, events);
+
+pub struct NewInvoice {
+    customer: String,
+    amount: i32,
+}
+
+insertable! {
+    NewInvoice => invoices {
+        customer -> String,
+        amount -> i32,
+    }
+}
+
+fn connection_with_schemas() -> Connection {
+    let connection = connection();
+    connection.execute("CREATE SCHEMA billing").unwrap();
+    connection.execute("CREATE SCHEMA audit").unwrap();
+    connection.execute("CREATE TABLE billing.invoices (
+        id SERIAL PRIMARY KEY,
+        customer VARCHAR NOT NULL,
+        amount INTEGER NOT NULL
+    )").unwrap();
+    connection.execute("CREATE TABLE audit.events (
+        event_id SERIAL PRIMARY KEY,
+        invoice_id INTEGER NOT NULL,
+        description VARCHAR NOT NULL
+    )").unwrap();
+    connection
+}
+
+#[test]
+fn insert_and_select_from_table_in_schema() {
+    let connection = connection_with_schemas();
+    let new_invoices: &[_] = &[
+        NewInvoice { customer: "Sean".to_string(), amount: 10 },
+        NewInvoice { customer: "Tess".to_string(), amount: 20 },
+    ];
+    let inserted: Vec<(i32, String, i32)> = connection.insert(&invoices::table, new_invoices)
+        .unwrap().collect();
+
+    let expected_data = vec![(1, "Sean".to_string(), 10), (2, "Tess".to_string(), 20)];
+    assert_eq!(expected_data, inserted);
+
+    let source = invoices::table.filter(invoices::amount.gt(15)).select(invoices::customer);
+    let data: Vec<String> = source.load(&connection).unwrap().collect();
+    assert_eq!(vec!["Tess".to_string()], data);
+}
+
+#[test]
+fn update_table_in_schema() {
+    let connection = connection_with_schemas();
+    connection.execute("INSERT INTO billing.invoices (customer, amount) VALUES ('Sean', 10)")
+        .unwrap();
+
+    let command = update(invoices::table.filter(invoices::id.eq(1))).set(invoices::amount.eq(30));
+    connection.execute_returning_count(&command).unwrap();
+
+    let invoice: Option<(i32, String, i32)> = connection.find(invoices::table, 1).unwrap();
+    assert_eq!(Some((1, "Sean".to_string(), 30)), invoice);
+}
+
+#[test]
+fn join_tables_in_different_schemas() {
+    let connection = connection_with_schemas();
+    connection.execute("INSERT INTO billing.invoices (customer, amount) VALUES ('Sean', 10)")
+        .unwrap();
+    connection.execute("INSERT INTO audit.events (invoice_id, description) VALUES
+        (1, 'Created'),
+        (1, 'Paid')
+    ").unwrap();
+
+    let source = invoices::table.inner_join(events::table)
+        .select((invoices::customer, events::description))
+        .order(events::event_id);
+    let expected_data = vec![
+        ("Sean".to_string(), "Created".to_string()),
+        ("Sean".to_string(), "Paid".to_string()),
+    ];
+    let data: Vec<(String, String)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
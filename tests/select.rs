@@ -44,7 +44,7 @@ fn with_safe_select() {
 
     let select_id = users.select(id);
     let select_name = users.select(name);
-    let ids: Vec<_> = select_id.load(&connection)
+    let ids: Vec<i32> = select_id.load(&connection)
         .unwrap().collect();
     let names: Vec<String> = select_name.load(&connection)
         .unwrap().collect();
@@ -138,7 +138,7 @@ fn selecting_expression_with_bind_param() {
 
     let source = users.select(name.eq("Sean".to_string()));
     let expected_data = vec![true, false];
-    let actual_data: Vec<_> = source.load(&connection).unwrap().collect();
+    let actual_data: Vec<bool> = source.load(&connection).unwrap().collect();
 
     assert_eq!(expected_data, actual_data);
 }
@@ -1,4 +1,10 @@
 extern crate yaqb;
+#[cfg(feature = "numeric")]
+extern crate bigdecimal;
+#[cfg(feature = "uuid")]
+extern crate uuid;
+#[cfg(feature = "chrono")]
+extern crate chrono;
 
 use self::yaqb::*;
 use self::yaqb::types::*;
@@ -237,6 +243,121 @@ fn pg_array_containing_null() {
     assert_eq!(expected, data);
 }
 
+#[test]
+fn pg_multi_dimensional_array_from_sql() {
+    use yaqb::types::structs::{PgArray, ArrayDimension};
+
+    let query = "SELECT ARRAY[[1, 2, 3], [4, 5, 6]]";
+    assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]],
+               query_single_value::<Array<Array<Integer>>, Vec<Vec<i32>>>(query));
+    let expected_value = PgArray {
+        dimensions: vec![ArrayDimension::new(2), ArrayDimension::new(3)],
+        elements: vec![1, 2, 3, 4, 5, 6],
+    };
+    assert_eq!(expected_value, query_single_value::<Array<Integer>, PgArray<i32>>(query));
+    assert_eq!(Vec::<Vec<i32>>::new(),
+               query_single_value::<Array<Array<Integer>>, Vec<Vec<i32>>>("SELECT '{}'::int[]"));
+}
+
+#[test]
+fn pg_array_with_lower_bound_from_sql() {
+    use yaqb::types::structs::{PgArray, ArrayDimension};
+
+    let query = "SELECT '[0:2]={1,2,3}'::int[]";
+    let expected_value = PgArray {
+        dimensions: vec![ArrayDimension { length: 3, lower_bound: 0 }],
+        elements: vec![1, 2, 3],
+    };
+    assert_eq!(expected_value, query_single_value::<Array<Integer>, PgArray<i32>>(query));
+}
+
+#[test]
+fn arrays_of_the_wrong_shape_are_an_error() {
+    use yaqb::types::structs::{PgArray, ArrayDimension};
+
+    let matrix = PgArray {
+        dimensions: vec![ArrayDimension::new(2), ArrayDimension::new(1)],
+        elements: vec![1, 2],
+    };
+    let mut bytes = Vec::new();
+    ToSql::<Array<Integer>>::to_sql(&matrix, &mut bytes).unwrap();
+    let result: Result<Vec<i32>, _> = FromSql::<Array<Integer>>::from_sql(Some(&bytes));
+    assert!(result.is_err());
+
+    let offset = PgArray {
+        dimensions: vec![ArrayDimension { length: 2, lower_bound: 0 }],
+        elements: vec![1, 2],
+    };
+    let mut bytes = Vec::new();
+    ToSql::<Array<Integer>>::to_sql(&offset, &mut bytes).unwrap();
+    let result: Result<Vec<i32>, _> = FromSql::<Array<Integer>>::from_sql(Some(&bytes));
+    assert!(result.is_err());
+    let result: Result<Vec<Vec<i32>>, _> = FromSql::<Array<Array<Integer>>>::from_sql(Some(&bytes));
+    assert!(result.is_err());
+
+    let ragged = vec![vec![1, 2], vec![3]];
+    let result = ToSql::<Array<Array<Integer>>>::to_sql(&ragged, &mut Vec::new());
+    assert!(result.is_err());
+
+    let too_few_elements = PgArray {
+        dimensions: vec![ArrayDimension::new(3)],
+        elements: vec![1, 2],
+    };
+    let result = ToSql::<Array<Integer>>::to_sql(&too_few_elements, &mut Vec::new());
+    assert!(result.is_err());
+}
+
+#[test]
+fn arrays_with_empty_inner_dimensions_have_empty_sub_arrays() {
+    use yaqb::types::structs::{PgArray, ArrayDimension};
+
+    let empty_rows = PgArray::<i32> {
+        dimensions: vec![ArrayDimension::new(2), ArrayDimension::new(0)],
+        elements: vec![],
+    };
+    let mut bytes = Vec::new();
+    ToSql::<Array<Integer>>::to_sql(&empty_rows, &mut bytes).unwrap();
+    let result: Vec<Vec<i32>> = FromSql::<Array<Array<Integer>>>::from_sql(Some(&bytes)).unwrap();
+    assert_eq!(vec![Vec::<i32>::new(), Vec::new()], result);
+}
+
+#[test]
+fn arrays_with_too_many_elements_are_an_error() {
+    use yaqb::types::structs::{PgArray, ArrayDimension};
+
+    let huge = PgArray::<i32> {
+        dimensions: vec![ArrayDimension::new(i32::max_value()); 3],
+        elements: vec![],
+    };
+    let result = ToSql::<Array<Integer>>::to_sql(&huge, &mut Vec::new());
+    assert!(result.is_err());
+}
+
+#[test]
+fn to_sql_multi_dimensional_array() {
+    use yaqb::types::structs::{PgArray, ArrayDimension};
+
+    assert!(query_to_sql_equality::<Array<Array<Integer>>, Vec<Vec<i32>>>(
+            "ARRAY[[1, 2], [3, 4]]", vec![vec![1, 2], vec![3, 4]]));
+    assert!(!query_to_sql_equality::<Array<Array<Integer>>, Vec<Vec<i32>>>(
+            "ARRAY[1, 2, 3, 4]", vec![vec![1, 2], vec![3, 4]]));
+    assert!(query_to_sql_equality::<Array<Array<Integer>>, Vec<Vec<i32>>>(
+            "'{}'::int[]", Vec::new()));
+    let value = PgArray {
+        dimensions: vec![ArrayDimension { length: 3, lower_bound: 0 }],
+        elements: vec![1, 2, 3],
+    };
+    assert!(query_to_sql_equality::<Array<Integer>, PgArray<i32>>(
+            "'[0:2]={1,2,3}'::int[]", value.clone()));
+    assert!(!query_to_sql_equality::<Array<Integer>, PgArray<i32>>("ARRAY[1, 2, 3]", value));
+}
+
+#[test]
+fn to_sql_array_containing_null() {
+    assert!(query_to_sql_equality::<Array<Nullable<Integer>>, Vec<Option<i32>>>(
+            "ARRAY[1, NULL, 3]", vec![Some(1), None, Some(3)]));
+}
+
 #[test]
 fn timestamp_from_sql() {
     use yaqb::types::structs::PgTimestamp;
@@ -263,6 +384,281 @@ fn pg_timestamp_to_sql_timestamp() {
     assert!(!query_to_sql_equality::<Timestamp, PgTimestamp>(expected_non_equal_value, value));
 }
 
+#[test]
+fn pg_numeric_from_sql() {
+    use yaqb::types::structs::PgNumeric;
+
+    let query = "SELECT 1.0::numeric";
+    let expected_value = PgNumeric::Positive { weight: 0, scale: 1, digits: vec![1] };
+    assert_eq!(expected_value, query_single_value::<Numeric, PgNumeric>(query));
+    let query = "SELECT -31.0::numeric";
+    let expected_value = PgNumeric::Negative { weight: 0, scale: 1, digits: vec![31] };
+    assert_eq!(expected_value, query_single_value::<Numeric, PgNumeric>(query));
+    let query = "SELECT 1234567890.12345::numeric";
+    let expected_value = PgNumeric::Positive {
+        weight: 2,
+        scale: 5,
+        digits: vec![12, 3456, 7890, 1234, 5000],
+    };
+    assert_eq!(expected_value, query_single_value::<Numeric, PgNumeric>(query));
+    let query = "SELECT 'NaN'::numeric";
+    assert_eq!(PgNumeric::NaN, query_single_value::<Numeric, PgNumeric>(query));
+}
+
+#[test]
+fn pg_numeric_to_sql_numeric() {
+    use yaqb::types::structs::PgNumeric;
+
+    let value = PgNumeric::Positive { weight: 0, scale: 2, digits: vec![12, 3400] };
+    assert!(query_to_sql_equality::<Numeric, PgNumeric>("12.34::numeric(4, 2)", value));
+    let value = PgNumeric::Negative { weight: -1, scale: 4, digits: vec![1] };
+    assert!(query_to_sql_equality::<Numeric, PgNumeric>("-0.0001::numeric", value.clone()));
+    assert!(!query_to_sql_equality::<Numeric, PgNumeric>("0.0001::numeric", value));
+    assert!(query_to_sql_equality::<Numeric, PgNumeric>("'NaN'::numeric", PgNumeric::NaN));
+}
+
+#[cfg(feature = "numeric")]
+#[test]
+fn big_decimal_roundtrips_through_numeric() {
+    use self::bigdecimal::BigDecimal;
+    use std::str::FromStr;
+
+    let values = ["0", "1", "-1", "10000", "0.0001", "-12.34", "1234567890.12345",
+        "100000000000000000000.000000000000000000001", "0.50", "1e5"];
+    for value in values.iter() {
+        let decimal = BigDecimal::from_str(value).unwrap();
+        let query = format!("SELECT {}::numeric", decimal);
+        assert_eq!(decimal, query_single_value::<Numeric, BigDecimal>(&query));
+        assert!(query_to_sql_equality::<Numeric, BigDecimal>(&format!("{}::numeric", decimal),
+            decimal.clone()));
+    }
+}
+
+#[cfg(feature = "numeric")]
+#[test]
+fn big_decimals_outside_numeric_range_are_an_error() {
+    use self::bigdecimal::BigDecimal;
+    use std::str::FromStr;
+
+    let too_precise = BigDecimal::from_str("1e-70000").unwrap();
+    let result = ToSql::<Numeric>::to_sql(&too_precise, &mut Vec::new());
+    assert!(result.is_err());
+    let too_large = BigDecimal::from_str("1e140000").unwrap();
+    let result = ToSql::<Numeric>::to_sql(&too_large, &mut Vec::new());
+    assert!(result.is_err());
+}
+
+#[test]
+fn pg_range_from_sql() {
+    use yaqb::types::structs::{PgRange, RangeBound};
+
+    let query = "SELECT '[1,5]'::int4range";
+    let expected_value = PgRange::from(1..6);
+    assert_eq!(expected_value, query_single_value::<Range<Integer>, PgRange<i32>>(query));
+    let query = "SELECT '(,5)'::int8range";
+    let expected_value = PgRange::new(RangeBound::Unbounded, RangeBound::Exclusive(5));
+    assert_eq!(expected_value, query_single_value::<Range<BigInt>, PgRange<i64>>(query));
+    let query = "SELECT 'empty'::int4range";
+    assert_eq!(PgRange::Empty, query_single_value::<Range<Integer>, PgRange<i32>>(query));
+}
+
+#[test]
+fn pg_range_to_sql_range() {
+    use yaqb::types::structs::{PgRange, RangeBound};
+
+    assert!(query_to_sql_equality::<Range<Integer>, PgRange<i32>>("'[1,5)'::int4range", PgRange::from(1..5)));
+    let value = PgRange::new(RangeBound::Inclusive(1), RangeBound::Unbounded);
+    assert!(query_to_sql_equality::<Range<Integer>, PgRange<i32>>("'[1,)'::int4range", value));
+    assert!(!query_to_sql_equality::<Range<Integer>, PgRange<i32>>("'empty'::int4range", PgRange::from(1..5)));
+}
+
+#[test]
+fn pg_uuid_from_sql() {
+    use yaqb::types::structs::PgUuid;
+
+    let query = "SELECT 'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'::uuid";
+    let expected_value = PgUuid([0xa0, 0xee, 0xbc, 0x99, 0x9c, 0x0b, 0x4e, 0xf8,
+                                 0xbb, 0x6d, 0x6b, 0xb9, 0xbd, 0x38, 0x0a, 0x11]);
+    assert_eq!(expected_value, query_single_value::<Uuid, PgUuid>(query));
+}
+
+#[test]
+fn pg_uuid_to_sql_uuid() {
+    use yaqb::types::structs::PgUuid;
+
+    let value = PgUuid([0xa0, 0xee, 0xbc, 0x99, 0x9c, 0x0b, 0x4e, 0xf8,
+                        0xbb, 0x6d, 0x6b, 0xb9, 0xbd, 0x38, 0x0a, 0x11]);
+    let expected_value = "'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'::uuid";
+    assert!(query_to_sql_equality::<Uuid, PgUuid>(expected_value, value));
+    let expected_non_equal_value = "'00000000-0000-0000-0000-000000000000'::uuid";
+    assert!(!query_to_sql_equality::<Uuid, PgUuid>(expected_non_equal_value, value));
+}
+
+#[test]
+fn pg_uuid_array_from_sql() {
+    use yaqb::types::structs::PgUuid;
+
+    let query = "SELECT ARRAY['00000000-0000-0000-0000-000000000000'::uuid, \
+        'ffffffff-ffff-ffff-ffff-ffffffffffff'::uuid]";
+    let expected_value = vec![PgUuid([0; 16]), PgUuid([0xff; 16])];
+    assert_eq!(expected_value, query_single_value::<Array<Uuid>, Vec<PgUuid>>(query));
+    assert!(query_to_sql_equality::<Array<Uuid>, Vec<PgUuid>>(
+        "ARRAY['00000000-0000-0000-0000-000000000000'::uuid]", vec![PgUuid([0; 16])]));
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid_roundtrips_through_uuid_column() {
+    use self::uuid::Uuid as UuidValue;
+
+    let value = UuidValue::parse_str("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11").unwrap();
+    let query = "SELECT 'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'::uuid";
+    assert_eq!(value, query_single_value::<Uuid, UuidValue>(query));
+    assert!(query_to_sql_equality::<Uuid, UuidValue>(
+        "'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'::uuid", value));
+}
+
+#[test]
+fn timestamptz_from_sql() {
+    use yaqb::types::structs::PgTimestamp;
+
+    let connection = connection();
+    connection.execute("SET TIME ZONE 'UTC'").unwrap();
+    let query = "SELECT '2015-11-13 13:26:48.041057-07'::timestamptz";
+    let expected_value = PgTimestamp(500761608041057);
+    let value = connection.query_sql::<Timestamptz, PgTimestamp>(query).unwrap().nth(0);
+    assert_eq!(Some(expected_value), value);
+}
+
+#[test]
+fn pg_timestamp_to_sql_timestamptz() {
+    use yaqb::types::structs::PgTimestamp;
+
+    let expected_value = "'2015-11-13 20:26:48.041057+00'::timestamptz";
+    let value = PgTimestamp(500761608041057);
+    assert!(query_to_sql_equality::<Timestamptz, PgTimestamp>(expected_value, value));
+    let expected_value = "'2015-11-13 13:26:48.041057-07'::timestamptz";
+    assert!(query_to_sql_equality::<Timestamptz, PgTimestamp>(expected_value, value));
+    let expected_non_equal_value = "'2015-11-13 13:26:48.041057+00'::timestamptz";
+    assert!(!query_to_sql_equality::<Timestamptz, PgTimestamp>(expected_non_equal_value, value));
+}
+
+#[cfg(feature = "chrono")]
+mod with_chrono {
+    use super::chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
+    use super::{query_single_value, query_to_sql_equality};
+    use yaqb::types::*;
+
+    #[test]
+    fn naive_date_time_roundtrips_through_timestamp() {
+        let value = NaiveDate::from_ymd_opt(2015, 11, 13).unwrap()
+            .and_hms_micro_opt(13, 26, 48, 41057).unwrap();
+        let query = "SELECT '2015-11-13 13:26:48.041057'::timestamp";
+        assert_eq!(value, query_single_value::<Timestamp, _>(query));
+        assert!(query_to_sql_equality::<Timestamp, _>("'2015-11-13 13:26:48.041057'::timestamp", value));
+
+        let value = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(value, query_single_value::<Timestamp, _>("SELECT '1970-01-01'::timestamp"));
+        assert!(query_to_sql_equality::<Timestamp, _>("'1970-01-01'::timestamp", value));
+    }
+
+    #[test]
+    fn date_time_utc_roundtrips_through_timestamptz() {
+        let value = Utc.with_ymd_and_hms(2015, 11, 13, 20, 26, 48).unwrap();
+        let query = "SELECT '2015-11-13 13:26:48-07'::timestamptz";
+        assert_eq!(value, query_single_value::<Timestamptz, DateTime<Utc>>(query));
+        assert!(query_to_sql_equality::<Timestamptz, _>("'2015-11-13 13:26:48-07'::timestamptz", value));
+    }
+
+    #[test]
+    fn naive_date_roundtrips_through_date() {
+        let value = NaiveDate::from_ymd_opt(2015, 11, 13).unwrap();
+        assert_eq!(value, query_single_value::<Date, _>("SELECT '2015-11-13'::date"));
+        assert!(query_to_sql_equality::<Date, _>("'2015-11-13'::date", value));
+
+        let value = NaiveDate::from_ymd_opt(1999, 12, 31).unwrap();
+        assert_eq!(value, query_single_value::<Date, _>("SELECT '1999-12-31'::date"));
+        assert!(query_to_sql_equality::<Date, _>("'1999-12-31'::date", value));
+    }
+
+    #[test]
+    fn naive_time_roundtrips_through_time() {
+        let value = NaiveTime::from_hms_micro_opt(13, 26, 48, 41057).unwrap();
+        assert_eq!(value, query_single_value::<Time, _>("SELECT '13:26:48.041057'::time"));
+        assert!(query_to_sql_equality::<Time, _>("'13:26:48.041057'::time", value));
+    }
+
+    #[test]
+    fn infinite_values_are_an_error() {
+        use super::chrono::NaiveDateTime;
+        use yaqb::types::FromSql;
+
+        let infinity = [0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+        let result: Result<NaiveDateTime, _> = FromSql::<Timestamp>::from_sql(Some(&infinity));
+        assert!(result.is_err());
+        let negative_infinity = [0x80, 0x00, 0x00, 0x00];
+        let result: Result<NaiveDate, _> = FromSql::<Date>::from_sql(Some(&negative_infinity));
+        assert!(result.is_err());
+    }
+}
+
+mod with_std_time {
+    use std::time::{Duration, UNIX_EPOCH};
+    use super::{query_single_value, query_to_sql_equality};
+    use yaqb::expression::dsl::*;
+    use yaqb::types::*;
+    use yaqb::types::structs::PgInterval;
+
+    #[test]
+    fn system_time_roundtrips_through_timestamp() {
+        let value = UNIX_EPOCH + Duration::new(1447421208, 41057000);
+        let query = "SELECT '2015-11-13 13:26:48.041057'::timestamp";
+        assert_eq!(value, query_single_value::<Timestamp, _>(query));
+        assert!(query_to_sql_equality::<Timestamp, _>("'2015-11-13 13:26:48.041057'::timestamp", value));
+
+        let value = UNIX_EPOCH + Duration::new(946684799, 500000000);
+        let query = "SELECT '1999-12-31 23:59:59.5'::timestamp";
+        assert_eq!(value, query_single_value::<Timestamp, _>(query));
+        assert!(query_to_sql_equality::<Timestamp, _>("'1999-12-31 23:59:59.5'::timestamp", value));
+    }
+
+    #[test]
+    fn system_time_roundtrips_through_timestamptz() {
+        let value = UNIX_EPOCH + Duration::new(1447446408, 0);
+        let query = "SELECT '2015-11-13 13:26:48-07'::timestamptz";
+        assert_eq!(value, query_single_value::<Timestamptz, _>(query));
+        assert!(query_to_sql_equality::<Timestamptz, _>("'2015-11-13 13:26:48-07'::timestamptz", value));
+    }
+
+    #[test]
+    fn duration_roundtrips_through_interval() {
+        let value = Duration::new(5430, 1000);
+        assert_eq!(value, query_single_value::<Interval, _>("SELECT '1 hour 30 minutes 30.000001 seconds'::interval"));
+        assert!(query_to_sql_equality::<Interval, _>("'1 hour 30 minutes 30.000001 seconds'::interval", value));
+        assert!(query_to_sql_equality::<Interval, _>("'90 minutes'::interval", Duration::from_secs(5400)));
+        assert_eq!(90.minutes(), PgInterval::from_microseconds(5_400_000_000));
+    }
+
+    #[test]
+    fn intervals_with_days_or_months_cannot_be_durations() {
+        use yaqb::types::FromSql;
+
+        let bytes = |interval: PgInterval| {
+            let mut bytes = Vec::new();
+            ToSql::<Interval>::to_sql(&interval, &mut bytes).unwrap();
+            bytes
+        };
+        let one_day: Result<Duration, _> = FromSql::<Interval>::from_sql(Some(&bytes(1.day())));
+        assert!(one_day.is_err());
+        let one_month: Result<Duration, _> = FromSql::<Interval>::from_sql(Some(&bytes(1.month())));
+        assert!(one_month.is_err());
+        let negative: Result<Duration, _> = FromSql::<Interval>::from_sql(Some(&bytes((-1).second())));
+        assert!(negative.is_err());
+        let one_hour: Result<Duration, _> = FromSql::<Interval>::from_sql(Some(&bytes(1.hour())));
+        assert_eq!(Duration::from_secs(3600), one_hour.unwrap());
+    }
+}
+
 fn query_single_value<T: NativeSqlType, U: Queriable<T>>(sql: &str) -> U {
     let connection = connection();
     let mut cursor = connection.query_sql::<T, U>(sql)
@@ -101,3 +101,231 @@ fn update_with_struct_as_changes() {
 
     assert_eq!(Some(expected_user), user);
 }
+
+pub struct UserChanges {
+    name: Option<String>,
+    hair_color: Option<Option<String>>,
+}
+
+changeset! {
+    #[skip_none]
+    UserChanges => users {
+        name -> Option<String>,
+        hair_color -> Option<Option<String>>,
+    }
+}
+
+#[test]
+fn update_with_changeset_skips_none_fields() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    connection.execute("UPDATE users SET hair_color = 'black'").unwrap();
+
+    let changes = UserChanges { name: Some("Jim".to_string()), hair_color: None };
+    let command = update(users.filter(id.eq(1))).set(&changes);
+    let user = connection.query_one(command).unwrap();
+    assert_eq!(Some(User::with_hair_color(1, "Jim", "black")), user);
+
+    let changes = UserChanges { name: None, hair_color: Some(Some("blue".to_string())) };
+    let command = update(users.filter(id.eq(1))).set(&changes);
+    let user = connection.query_one(command).unwrap();
+    assert_eq!(Some(User::with_hair_color(1, "Jim", "blue")), user);
+}
+
+pub struct RenameWithOptionalHairColor {
+    name: String,
+    hair_color: Option<Option<String>>,
+}
+
+changeset! {
+    #[skip_none]
+    RenameWithOptionalHairColor => users {
+        name -> String,
+        hair_color -> Option<Option<String>>,
+    }
+}
+
+#[test]
+fn update_with_changeset_mixing_required_and_optional_fields() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    connection.execute("UPDATE users SET hair_color = 'black'").unwrap();
+
+    let changes = RenameWithOptionalHairColor { name: "Jim".to_string(), hair_color: None };
+    let command = update(users.filter(id.eq(1))).set(&changes);
+    let user = connection.query_one(command).unwrap();
+    assert_eq!(Some(User::with_hair_color(1, "Jim", "black")), user);
+
+    let changes = RenameWithOptionalHairColor {
+        name: "Bob".to_string(),
+        hair_color: Some(Some("blue".to_string())),
+    };
+    let command = update(users.filter(id.eq(1))).set(&changes);
+    let user = connection.query_one(command).unwrap();
+    assert_eq!(Some(User::with_hair_color(1, "Bob", "blue")), user);
+}
+
+#[test]
+fn update_with_changeset_can_explicitly_set_null() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    connection.execute("UPDATE users SET hair_color = 'black'").unwrap();
+
+    let changes = UserChanges { name: None, hair_color: Some(None) };
+    let command = update(users.filter(id.eq(1))).set(&changes);
+    let user = connection.query_one(command).unwrap();
+    assert_eq!(Some(User::new(1, "Sean")), user);
+}
+
+#[test]
+fn update_with_changeset_where_every_field_is_skipped_is_an_error() {
+    use schema::users::dsl::*;
+    use yaqb::result::Error;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let changes = UserChanges { name: None, hair_color: None };
+    let command = update(users.filter(id.eq(1))).set(&changes);
+    match connection.execute_returning_count(&command) {
+        Err(Error::QueryBuilderError(_)) => {},
+        result => panic!("Expected a query builder error, got {:?}", result),
+    }
+}
+
+table! {
+    pages {
+        id -> Serial,
+        title -> VarChar,
+        view_count -> Integer,
+        updated_at -> Timestamp,
+    }
+}
+
+numeric_expr!(pages::view_count);
+
+fn connection_with_pages() -> Connection {
+    let connection = connection();
+    connection.execute("CREATE TABLE pages (
+        id SERIAL PRIMARY KEY,
+        title VARCHAR NOT NULL DEFAULT 'Untitled',
+        view_count INTEGER NOT NULL DEFAULT 0,
+        updated_at TIMESTAMP NOT NULL DEFAULT '2015-01-01'
+    )").unwrap();
+    connection.execute("INSERT INTO pages (title, view_count) VALUES ('Home', 10), ('About', 5)")
+        .unwrap();
+    connection
+}
+
+#[test]
+fn update_column_to_expression_of_itself() {
+    use self::pages::dsl::*;
+
+    let connection = connection_with_pages();
+
+    let command = update(pages.filter(id.eq(1))).set(view_count.eq(view_count + 1));
+    connection.execute_returning_count(&command).unwrap();
+
+    let data: Vec<i32> = pages.select(view_count).order(id).load(&connection).unwrap().collect();
+    assert_eq!(vec![11, 5], data);
+}
+
+#[test]
+fn update_column_to_sql_function() {
+    use self::pages::dsl::*;
+    use yaqb::expression::dsl::now;
+
+    let connection = connection_with_pages();
+
+    let command = update(pages.filter(id.eq(2))).set(updated_at.eq(now));
+    connection.execute_returning_count(&command).unwrap();
+
+    let data: Vec<String> = pages.select(title).filter(updated_at.eq(now)).load(&connection)
+        .unwrap().collect();
+    assert_eq!(vec!["About".to_string()], data);
+}
+
+#[test]
+fn update_with_mix_of_values_and_expressions() {
+    use self::pages::dsl::*;
+
+    let connection = connection_with_pages();
+
+    let command = update(pages.filter(id.eq(1)))
+        .set((title.eq("Welcome"), view_count.eq(view_count * 2)));
+    connection.execute_returning_count(&command).unwrap();
+
+    let data: Vec<(String, i32)> = pages.select((title, view_count)).order(id)
+        .load(&connection).unwrap().collect();
+    let expected_data = vec![("Welcome".to_string(), 20), ("About".to_string(), 5)];
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn update_column_to_default() {
+    use self::pages::dsl::*;
+    use yaqb::expression::dsl::default;
+
+    let connection = connection_with_pages();
+
+    let command = update(pages.filter(id.eq(1)))
+        .set((title.eq(default()), view_count.eq(default())));
+    connection.execute_returning_count(&command).unwrap();
+
+    let data: Vec<(String, i32)> = pages.select((title, view_count)).order(id)
+        .load(&connection).unwrap().collect();
+    let expected_data = vec![("Untitled".to_string(), 0), ("About".to_string(), 5)];
+    assert_eq!(expected_data, data);
+}
+
+fn connection_with_users_and_posts() -> Connection {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    setup_posts_table(&connection);
+    connection.execute("INSERT INTO posts (user_id, title) VALUES
+        (1, 'Hello'),
+        (2, 'World')
+    ").unwrap();
+    connection
+}
+
+#[test]
+fn update_filtered_by_joined_table() {
+    let connection = connection_with_users_and_posts();
+
+    let source = users::table.inner_join(posts::table).filter(posts::title.eq("World"));
+    let command = update(source).set(users::name.eq("Jim"));
+    assert_eq!(Some(1), connection.execute_returning_count(&command).ok());
+
+    let data: Vec<String> = users::table.select(users::name).order(users::id)
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec!["Sean".to_string(), "Jim".to_string()], data);
+}
+
+#[test]
+fn update_from_joined_table_with_filter_on_both_tables() {
+    let connection = connection_with_users_and_posts();
+
+    let source = users::table.inner_join(posts::table)
+        .filter(users::id.eq(1).and(posts::title.eq("Hello")));
+    let command = update(source).set(users::hair_color.eq("Black"));
+    assert_eq!(Some(1), connection.execute_returning_count(&command).ok());
+
+    let data: Vec<Option<String>> = users::table.select(users::hair_color).order(users::id)
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec![Some("Black".to_string()), None], data);
+}
+
+#[test]
+fn update_column_to_value_from_joined_table() {
+    let connection = connection_with_users_and_posts();
+
+    let source = users::table.inner_join(posts::table).filter(users::id.eq(2));
+    let command = update(source).set(users::name.eq(posts::title));
+    assert_eq!(Some(1), connection.execute_returning_count(&command).ok());
+
+    let data: Vec<String> = users::table.select(users::name).order(users::id)
+        .load(&connection).unwrap().collect();
+    assert_eq!(vec!["Sean".to_string(), "World".to_string()], data);
+}
@@ -0,0 +1,104 @@
+use schema::*;
+use yaqb::*;
+use yaqb::query_builder::{with, with_recursive};
+
+table! {
+    seans_posts {
+        id -> Integer,
+        user_id -> Integer,
+        title -> VarChar,
+    }
+}
+
+joinable!(seans_posts -> users (user_id = id));
+allow_tables_to_appear_in_same_query!(seans_posts, users);
+
+fn connection_with_posts() -> Connection {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    setup_posts_table(&connection);
+    connection.execute("INSERT INTO posts (user_id, title) VALUES
+        (1, 'Hello'),
+        (2, 'World'),
+        (1, 'Goodbye')
+    ").unwrap();
+    connection
+}
+
+#[test]
+fn select_from_cte() {
+    let connection = connection_with_posts();
+
+    let cte = posts::table.filter(posts::user_id.eq(1))
+        .select((posts::id, posts::user_id, posts::title));
+    let source = with(seans_posts::table, cte)
+        .query(seans_posts::table.select(seans_posts::title).filter(seans_posts::id.gt(1)));
+    let expected_data = vec!["Goodbye".to_string()];
+    let data: Vec<String> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+#[test]
+fn join_against_cte() {
+    let connection = connection_with_posts();
+
+    let cte = posts::table.filter(posts::user_id.eq(1))
+        .select((posts::id, posts::user_id, posts::title));
+    let source = with(seans_posts::table, cte)
+        .query(users::table.inner_join(seans_posts::table).select((users::name, seans_posts::title)))
+        .order(seans_posts::id);
+    let expected_data = vec![
+        ("Sean".to_string(), "Hello".to_string()),
+        ("Sean".to_string(), "Goodbye".to_string()),
+    ];
+    let data: Vec<(String, String)> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}
+
+table! {
+    employees {
+        id -> Serial,
+        name -> VarChar,
+        manager_id -> Integer,
+    }
+}
+
+table! {
+    subordinates {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+joinable!(employees -> subordinates (manager_id = id));
+allow_tables_to_appear_in_same_query!(employees, subordinates);
+
+#[test]
+fn recursive_cte() {
+    let connection = connection();
+    connection.execute("CREATE TABLE employees (
+        id SERIAL PRIMARY KEY,
+        name VARCHAR NOT NULL,
+        manager_id INTEGER NOT NULL
+    )").unwrap();
+    connection.execute("INSERT INTO employees (name, manager_id) VALUES
+        ('Boss', 0),
+        ('Sean', 1),
+        ('Tess', 2),
+        ('Jim', 3),
+        ('Other Boss', 0)
+    ").unwrap();
+
+    let base_case = employees::table.filter(employees::name.eq("Sean"))
+        .select((employees::id, employees::name));
+    let recursive_case = employees::table.inner_join(subordinates::table)
+        .select((employees::id, employees::name));
+    let source = with_recursive(subordinates::table, base_case.union_all(recursive_case))
+        .query(subordinates::table.select(subordinates::name))
+        .order(subordinates::id);
+    let expected_data = vec!["Sean".to_string(), "Tess".to_string(), "Jim".to_string()];
+    let data: Vec<String> = source.load(&connection).unwrap().collect();
+
+    assert_eq!(expected_data, data);
+}